@@ -0,0 +1,230 @@
+from typing import Dict, List, Optional, Sequence
+
+from numpy import isscalar
+
+from .merge_obj import MergeObj
+from .point import Point
+
+
+class ClockTree:
+    """Rooted tree of wires driving a set of clock sinks
+
+    Nodes are numbered in the order they are added, and a node can only be
+    attached to a node added before it, so node 0 is the root. The wire
+    from a node to its parent may be longer than their Manhattan distance
+    (e.g. a snaking wire inserted to balance delays); its length is what
+    counts for wirelength and delay.
+
+    Examples:
+        >>> T = ClockTree()
+        >>> root = T.add_node(Point(0, 0))
+        >>> _ = T.add_node(Point(3, 0), root, sink=0)
+        >>> _ = T.add_node(Point(0, 2), root, length=3, sink=1)
+        >>> T.wirelength()
+        6
+        >>> T.skew(1)
+        0
+        >>> T.latency(1), T.max_skew()
+        (3, 0)
+    """
+
+    __slots__ = ("_positions", "_parents", "_lengths", "_sinks")
+
+    def __init__(self):
+        self._positions: List[Point] = []
+        self._parents: List[Optional[int]] = []
+        self._lengths: List = []
+        self._sinks: Dict[int, int] = {}  # sink index -> node
+
+    def __len__(self) -> int:
+        return len(self._positions)
+
+    def add_node(self, position: Point, parent=None, length=None, sink=None) -> int:
+        """Add a node to the tree
+
+        Args:
+            position (Point): the location of the node
+            parent (Optional[int]): the parent node, None for the root
+            length ([type]): the wire length to the parent, defaults to
+                their Manhattan distance
+            sink (Optional[int]): the sink index if the node is a sink
+
+        Returns:
+            int: the new node
+        """
+        if parent is None:
+            assert not self._positions, "the tree already has a root"
+            length = 0
+        else:
+            assert 0 <= parent < len(self._positions)
+            if length is None:
+                length = position.min_dist_with(self._positions[parent])
+        node = len(self._positions)
+        self._positions.append(position)
+        self._parents.append(parent)
+        self._lengths.append(length)
+        if sink is not None:
+            self._sinks[sink] = node
+        return node
+
+    def num_sinks(self) -> int:
+        return len(self._sinks)
+
+    def position(self, node: int) -> Point:
+        return self._positions[node]
+
+    def parent(self, node: int) -> Optional[int]:
+        return self._parents[node]
+
+    def length(self, node: int):
+        """Wire length from `node` to its parent (0 for the root)"""
+        return self._lengths[node]
+
+    def sink_node(self, sink: int) -> int:
+        return self._sinks[sink]
+
+    def wirelength(self):
+        """Total wire length of the tree"""
+        return sum(self._lengths)
+
+    def _path_lengths(self) -> List:
+        """Wire length from the root to every node"""
+        dist: List = []
+        for parent, length in zip(self._parents, self._lengths):
+            dist.append(length if parent is None else dist[parent] + length)
+        return dist
+
+    def latency(self, sink: int):
+        """Wire length from the root to a sink
+
+        Args:
+            sink (int): the sink index
+
+        Returns:
+            [type]: the sum of the wire lengths on the root-to-sink path
+        """
+        return self._path_lengths()[self._sinks[sink]]
+
+    def max_skew(self):
+        """Difference between the largest and the smallest sink latency
+
+        Returns:
+            [type]: the skew of the tree (0 if there are no sinks)
+        """
+        if not self._sinks:
+            return 0
+        dist = self._path_lengths()
+        latencies = [dist[n] for n in self._sinks.values()]
+        return max(latencies) - min(latencies)
+
+    def skew(self, sink: int):
+        """Delay of a sink relative to the earliest sink
+
+        Under the linear delay model the delay of a sink is the wire length
+        on its path from the root.
+
+        Args:
+            sink (int): the sink index
+
+        Returns:
+            [type]: the delay minus the minimum delay over all sinks
+        """
+        dist = self._path_lengths()
+        return dist[self._sinks[sink]] - min(dist[n] for n in self._sinks.values())
+
+
+class _Subtree:
+    __slots__ = ("region", "delay", "children", "sink", "length")
+
+    def __init__(self, region: MergeObj, delay, children=(), sink=None):
+        self.region = region  # merging segment in the rotated frame
+        self.delay = delay  # wire length down to every sink
+        self.children = children
+        self.sink = sink
+        self.length = 0  # wire length to the parent, set when merged
+
+
+def _merge(a: _Subtree, b: _Subtree) -> _Subtree:
+    """Zero-skew merge of two subtrees
+
+    The wire lengths `ea` and `eb` to the children satisfy
+    `a.delay + ea == b.delay + eb`. If their distance is too short for
+    this, the wire to the faster child is lengthened (snaked) beyond it.
+    """
+    d = a.region.min_dist_with(b.region)
+    ea = (d + b.delay - a.delay) / 2
+    if ea < 0:
+        ea, eb = 0, a.delay - b.delay
+    elif ea > d:
+        ea, eb = b.delay - a.delay, 0
+    else:
+        eb = d - ea
+    a.length, b.length = ea, eb
+    region = a.region.enlarge_with(ea).intersection_with(b.region.enlarge_with(eb))
+    return _Subtree(region, a.delay + ea, (a, b))
+
+
+def _clamp(coord, value):
+    """Nearest value in a rotated coordinate (scalar or Interval)"""
+    if isscalar(coord):
+        return coord
+    return min(max(value, coord.lb), coord.ub)
+
+
+class ClockTreeSynthesis:
+    """Zero-skew clock tree construction by deferred-merge embedding (DME)
+
+    Bottom-up, the two subtrees with the closest merging segments are merged
+    repeatedly. The merging segment of a new node is the set of locations
+    whose wire lengths to both children give equal delay; in the rotated
+    frame (u = x + y, v = x - y) it is the intersection of two squares.
+    Top-down, the root is placed on its merging segment and every child at
+    the point of its merging segment nearest to its parent.
+    """
+
+    @staticmethod
+    def build(sinks: Sequence[Point]) -> ClockTree:
+        """Build a zero-skew clock tree under the linear delay model
+
+        Args:
+            sinks (Sequence[Point]): the sink locations
+
+        Returns:
+            ClockTree: the tree, whose sink `i` is located at `sinks[i]`
+
+        Examples:
+            >>> T = ClockTreeSynthesis.build([Point(0, 0), Point(4, 2)])
+            >>> T.wirelength()
+            6.0
+            >>> print(T.position(0))
+            (1.0, 2.0)
+        """
+        tree = ClockTree()
+        nodes = [
+            _Subtree(MergeObj(p.x + p.y, p.x - p.y), 0, sink=i)
+            for i, p in enumerate(sinks)
+        ]
+        if not nodes:
+            return tree
+        while len(nodes) > 1:
+            _, i, j = min(
+                (nodes[i].region.min_dist_with(nodes[j].region), i, j)
+                for i in range(len(nodes))
+                for j in range(i + 1, len(nodes))
+            )
+            merged = _merge(nodes[i], nodes[j])
+            del nodes[j], nodes[i]
+            nodes.append(merged)
+
+        root = nodes[0]
+        stack = [(root, float("-inf"), float("-inf"), None)]
+        while stack:
+            node, u, v, parent = stack.pop()
+            u, v = _clamp(node.region.x, u), _clamp(node.region.y, v)
+            if node.sink is not None:
+                position = sinks[node.sink]
+            else:
+                position = Point((u + v) / 2, (u - v) / 2)
+            idx = tree.add_node(position, parent, node.length, node.sink)
+            stack += [(child, u, v, idx) for child in reversed(node.children)]
+        return tree
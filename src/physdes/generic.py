@@ -56,6 +56,68 @@ def intersection(lhs, rhs):
         return lhs
 
 
+def spatial_join(left, right):
+    """All overlapping pairs between two sets of shapes
+
+    This is a brute-force baseline that checks every cross pair; a spatial
+    index can be used later to speed it up.
+
+    Args:
+        left ([type]): list of shapes
+        right ([type]): list of shapes
+
+    Returns:
+        List[Tuple[int, int]]: index pairs (i, j) such that left[i] overlaps
+            right[j]
+
+    Examples:
+        >>> spatial_join([1, 2, 3], [3, 1])
+        [(0, 1), (2, 0)]
+    """
+    return [
+        (i, j)
+        for i, lhs in enumerate(left)
+        for j, rhs in enumerate(right)
+        if overlap(lhs, rhs)
+    ]
+
+
+def center(obj):
+    """Center of an object
+
+    Args:
+        obj ([type]): scalar or object that provides `center()`
+
+    Returns:
+        [type]: the center (the scalar itself for a scalar)
+
+    Examples:
+        >>> center(3)
+        3
+    """
+    if isscalar(obj):
+        return obj
+    return obj.center()
+
+
+def collection_centroid(shapes):
+    """Average of the centers of a collection of shapes
+
+    Args:
+        shapes ([type]): points, rectangles, segments, ...
+
+    Returns:
+        Optional[Point]: the centroid, or None for an empty collection
+    """
+    from .point import Point
+
+    if not shapes:
+        return None
+    centers = [center(s) for s in shapes]
+    n = len(centers)
+    return Point(sum(c.x for c in centers) / n, sum(c.y for c in centers) / n)
+
+
 def min_dist(lhs, rhs):
     """[summary]
 
@@ -90,3 +152,229 @@ def min_dist_change(lhs, rhs):
         return rhs.min_dist_change_with(lhs)
     else:
         return abs(lhs - rhs)
+
+
+def measure_of(obj):
+    """Measure of an object (length of an interval, area of a rectangle)
+
+    The measure is the natural size of an object in its own dimension: the
+    length of an Interval or a segment and the area of a Rect. Use
+    `area_of` or `perimeter_of` when the dimension matters.
+
+    Args:
+        obj ([type]): scalar or object that provides `measure()`
+
+    Returns:
+        [type]: the measure (zero for a scalar)
+
+    Examples:
+        >>> measure_of(3)
+        0
+    """
+    if isscalar(obj):
+        return 0
+    return obj.measure()
+
+
+def area_of(obj):
+    """Area of a shape
+
+    Provided by Rect, Polygon and RPolygon; a segment has zero area.
+
+    Args:
+        obj ([type]): object that provides `area()`
+
+    Returns:
+        [type]: the area
+
+    Examples:
+        >>> from physdes.interval import Interval
+        >>> from physdes.recti import Rect
+        >>> area_of(Rect(Interval(0, 2), Interval(0, 3)))
+        6
+    """
+    return obj.area()
+
+
+def perimeter_of(obj):
+    """Length of the boundary of a shape
+
+    Provided by Rect, Polygon (Euclidean), RPolygon and the segments.
+
+    Args:
+        obj ([type]): object that provides `perimeter()`
+
+    Returns:
+        [type]: the perimeter
+
+    Examples:
+        >>> from physdes.interval import Interval
+        >>> from physdes.recti import Rect
+        >>> perimeter_of(Rect(Interval(0, 2), Interval(0, 3)))
+        10
+    """
+    return obj.perimeter()
+
+
+def total_area(shapes):
+    """Naive sum of the measures of the shapes
+
+    Overlapping regions are counted more than once; see
+    `total_area_no_overlap` for the covered area.
+
+    Args:
+        shapes ([type]): list of shapes
+
+    Returns:
+        [type]: the sum of the measures
+    """
+    return sum(measure_of(s) for s in shapes)
+
+
+def total_area_no_overlap(rects):
+    """Total area of rectangles with overlapping regions counted once
+
+    This is another name for `covered_area`, as the counterpart of
+    `total_area`.
+
+    Args:
+        rects ([type]): list of rectangles
+
+    Returns:
+        [type]: the covered area
+    """
+    return covered_area(rects)
+
+
+def covered_area(rects):
+    """Area covered by at least one rectangle (Klee's measure)
+
+    Unlike `total_area`, a region covered by several rectangles is counted
+    once. The x-axis is cut into slabs at every rectangle boundary. Within
+    each slab, the covered y-intervals are merged and their lengths summed.
+
+    Args:
+        rects ([type]): list of rectangles
+
+    Returns:
+        [type]: the covered area
+
+    Examples:
+        >>> from physdes.interval import Interval
+        >>> from physdes.recti import Rect
+        >>> a = Rect(Interval(0, 2), Interval(0, 2))
+        >>> covered_area([a, Rect(Interval(1, 3), Interval(1, 3))])
+        7
+    """
+    xs = sorted(set(b for r in rects for b in (r.x.lb, r.x.ub)))
+    res = 0
+    for x0, x1 in zip(xs[:-1], xs[1:]):
+        spans = sorted(
+            (r.y.lb, r.y.ub) for r in rects if r.x.lb <= x0 and x1 <= r.x.ub
+        )
+        covered = 0
+        cur_lb = cur_ub = None
+        for lb, ub in spans:
+            if cur_ub is None or cur_ub < lb:
+                if cur_ub is not None:
+                    covered += cur_ub - cur_lb
+                cur_lb, cur_ub = lb, ub
+            elif cur_ub < ub:
+                cur_ub = ub
+        if cur_ub is not None:
+            covered += cur_ub - cur_lb
+        res += (x1 - x0) * covered
+    return res
+
+
+def transform_all(shapes, f):
+    """Replace every shape in place by its image under `f`
+
+    Args:
+        shapes ([type]): mutable list of shapes
+        f ([type]): function mapping a shape to the transformed shape
+
+    Examples:
+        >>> lst = [1, 2, 3]
+        >>> transform_all(lst, lambda a: a * 10)
+        >>> lst
+        [10, 20, 30]
+    """
+    for i, s in enumerate(shapes):
+        shapes[i] = f(s)
+
+
+def translate_all(shapes, v):
+    """Translate every shape in place by the same displacement
+
+    Point, Rect, the segments and Polygon all provide `translate` (and
+    `scale`, see `scale_all`), so a list may mix these shapes.
+
+    Args:
+        shapes ([type]): mutable list of shapes that provide `translate()`
+        v (Vector2): displacement
+    """
+    transform_all(shapes, lambda s: s.translate(v))
+
+
+def scale_all(shapes, factor):
+    """Scale every shape in place about the origin by the same factor
+
+    Args:
+        shapes ([type]): mutable list of shapes that provide `scale()`
+        factor ([type]): scaling factor (non-negative)
+
+    Examples:
+        >>> from physdes.point import Point
+        >>> lst = [Point(1, 2), Point(3, 0)]
+        >>> scale_all(lst, 10)
+        >>> print(*lst)
+        (10, 20) (30, 0)
+    """
+    transform_all(shapes, lambda s: s.scale(factor))
+
+
+_ORIENTATIONS = {
+    "R0": (0, None),
+    "R90": (1, None),
+    "R180": (2, None),
+    "R270": (3, None),
+    "MX": (0, "reflect_x"),
+    "MY": (0, "reflect_y"),
+    "MXR90": (1, "reflect_x"),
+    "MYR90": (1, "reflect_y"),
+}
+
+
+def apply_orientation(obj, orient: str):
+    """Apply one of the eight standard cell orientations to a shape
+
+    The shape follows the transform protocol, i.e. it provides
+    `translate(v)`, `rotate_90_ccw()` (counter-clockwise about the origin),
+    `reflect_x()` (mirror across the x-axis) and `reflect_y()` (mirror
+    across the y-axis). Point, Vector2, Rect, VSegment, HSegment and Polygon
+    all do; note that rotating a segment turns a VSegment into an HSegment
+    and vice versa. The orientations are named as in LEF/DEF: `R0`, `R90`, `R180`,
+    `R270`, `MX`, `MY`, `MXR90` and `MYR90`, where the mirrored rotations
+    mirror first and rotate afterwards.
+
+    Args:
+        obj ([type]): the shape
+        orient (str): the orientation
+
+    Returns:
+        [type]: the transformed shape
+
+    Examples:
+        >>> from physdes.point import Point
+        >>> print(apply_orientation(Point(3, 4), "R90"))
+        (-4, 3)
+        >>> print(apply_orientation(Point(3, 4), "MXR90"))
+        (4, 3)
+    """
+    turns, mirror = _ORIENTATIONS[orient]
+    if mirror is not None:
+        obj = getattr(obj, mirror)()
+    for _ in range(turns):
+        obj = obj.rotate_90_ccw()
+    return obj
@@ -1,19 +1,43 @@
+from enum import Enum
+
 from numpy import isscalar
 
 from .generic import min_dist, min_dist_change
 
 
+class Intersection(Enum):
+    """How two intervals meet, see `Interval.classify_intersection`"""
+
+    DISJOINT = 0
+    TOUCHING = 1
+    OVERLAPPING = 2
+
+
 class Interval:
+    """Closed interval [lb, ub]
+
+    Validity policy: an interval is valid when `lb <= ub`. The constructor
+    asserts it; `unchecked` skips the check for bounds coming from
+    untrusted sources, which `is_valid` and `normalized` then check or
+    repair. `overlaps` and `intersection_with` assert that their operands
+    are valid, so like the constructor they fail only in debug runs; other
+    operations such as `len` and `center` are not checked.
+    """
+
     __slots__ = ("_lb", "_ub")
 
-    def __init__(self, lb, ub):
+    def __init__(self, lb=0, ub=0):
         """[summary]
 
+        The default is the degenerate interval [0, 0], i.e. `point(0)`.
+
         Args:
             lb ([type]): [description]
             ub ([type]): [description]
 
         Examples:
+            >>> print(Interval())
+            [0, 0]
             >>> a = Interval(3, 4)
             >>> print(a)
             [3, 4]
@@ -22,6 +46,65 @@ class Interval:
         self._lb = lb
         self._ub = ub
 
+    @staticmethod
+    def point(x):
+        """The degenerate interval [x, x] of zero length
+
+        Examples:
+            >>> a = Interval.point(5)
+            >>> print(a)
+            [5, 5]
+            >>> a.len()
+            0
+        """
+        return Interval(x, x)
+
+    @staticmethod
+    def unit():
+        """The interval [0, 1]
+
+        Examples:
+            >>> print(Interval.unit())
+            [0, 1]
+        """
+        return Interval(0, 1)
+
+    @staticmethod
+    def unchecked(lb, ub):
+        """The interval [lb, ub] without checking that `lb <= ub`
+
+        Examples:
+            >>> a = Interval.unchecked(5, 1)
+            >>> a.is_valid()
+            False
+            >>> print(a.normalized())
+            [1, 5]
+        """
+        a = Interval.__new__(Interval)
+        a._lb = lb
+        a._ub = ub
+        return a
+
+    def is_valid(self) -> bool:
+        """Whether `lb <= ub`
+
+        Examples:
+            >>> Interval(3, 4).is_valid()
+            True
+        """
+        return not (self._ub < self._lb)
+
+    def normalized(self):
+        """The interval with its bounds swapped if they are inverted
+
+        Examples:
+            >>> print(Interval(3, 4).normalized())
+            [3, 4]
+        """
+        if self.is_valid():
+            return Interval(self._lb, self._ub)
+        return Interval(self._ub, self._lb)
+
     def __str__(self):
         """[summary]
 
@@ -35,6 +118,24 @@ class Interval:
         """
         return "[{self.lb}, {self.ub}]".format(self=self)
 
+    def display_units(self, unit: str) -> str:
+        """String form with a unit suffix on each bound
+
+        Args:
+            unit (str): the unit, e.g. "nm"
+
+        Returns:
+            str: the annotated string
+
+        Examples:
+            >>> a = Interval(1, 5)
+            >>> a.display_units("nm")
+            '[1nm, 5nm]'
+        """
+        return "[{}, {}]".format(
+            _with_units(self.lb, unit), _with_units(self.ub, unit)
+        )
+
     @property
     def lb(self):
         """[summary]
@@ -84,6 +185,64 @@ class Interval:
         """
         return self.ub - self.lb
 
+    def center(self):
+        """Midpoint of the interval
+
+        Returns:
+            [type]: the midpoint (by true division)
+
+        Examples:
+            >>> a = Interval(3, 8)
+            >>> a.center()
+            5.5
+        """
+        return (self.lb + self.ub) / 2
+
+    def center_floor(self):
+        """Midpoint rounded down, for integer intervals
+
+        Unlike `center`, which returns the exact (possibly fractional)
+        midpoint, the result stays an integer.
+
+        Examples:
+            >>> Interval(0, 3).center_floor(), Interval(-3, 0).center_floor()
+            (1, -2)
+        """
+        return (self.lb + self.ub) // 2
+
+    def center_ceil(self):
+        """Midpoint rounded up, for integer intervals
+
+        Examples:
+            >>> Interval(0, 3).center_ceil(), Interval(-3, 0).center_ceil()
+            (2, -1)
+        """
+        return -((-self.lb - self.ub) // 2)
+
+    def center_round(self):
+        """Midpoint rounded to the nearest integer, halves rounded up
+
+        Examples:
+            >>> Interval(0, 3).center_round(), Interval(-3, 0).center_round()
+            (2, -1)
+            >>> Interval(0, 4).center_round()
+            2
+        """
+        return (self.lb + self.ub + 1) // 2
+
+    def measure(self):
+        """Length of the interval
+
+        Returns:
+            [type]: the length
+
+        Examples:
+            >>> a = Interval(3, 8)
+            >>> a.measure()
+            5
+        """
+        return self.len()
+
     def __eq__(self, rhs) -> bool:
         """[summary]
 
@@ -101,6 +260,19 @@ class Interval:
         """
         return (self.lb, self.ub) == (rhs.lb, rhs.ub)
 
+    def __hash__(self):
+        """Hash consistent with `__eq__`
+
+        Intervals can be used in sets and as dict keys, e.g. to deduplicate
+        track ranges. An interval must not be modified in place (`+=` and
+        the like) while it is stored in one.
+
+        Examples:
+            >>> len({Interval(1, 3), Interval(1, 3), Interval(2, 3)})
+            2
+        """
+        return hash((self.lb, self.ub))
+
     def __lt__(self, rhs) -> bool:
         """[summary]
 
@@ -177,6 +349,50 @@ class Interval:
         """
         return not (self.ub < rhs)
 
+    def cmp_lexicographic(self, other) -> int:
+        """Three-way comparison by lower bound, then by upper bound
+
+        This is a total order consistent with `__eq__`. The comparison
+        operators are not: `<` means "entirely before", so overlapping
+        intervals are neither smaller nor greater than each other. Use this
+        with `functools.cmp_to_key` to sort intervals canonically.
+
+        Returns:
+            int: -1, 0 or 1
+
+        Examples:
+            >>> Interval(3, 9).cmp_lexicographic(Interval(3, 5))
+            1
+        """
+        a, b = (self.lb, self.ub), (other.lb, other.ub)
+        return (a > b) - (a < b)
+
+    def cmp_by_lb(self, other) -> int:
+        """Three-way comparison by lower bound
+
+        Usable with `functools.cmp_to_key` for sweep-line orderings.
+
+        Returns:
+            int: -1, 0 or 1
+
+        Examples:
+            >>> Interval(3, 9).cmp_by_lb(Interval(4, 5))
+            -1
+        """
+        return (self.lb > other.lb) - (self.lb < other.lb)
+
+    def cmp_by_ub(self, other) -> int:
+        """Three-way comparison by upper bound
+
+        Returns:
+            int: -1, 0 or 1
+
+        Examples:
+            >>> Interval(3, 9).cmp_by_ub(Interval(4, 5))
+            1
+        """
+        return (self.ub > other.ub) - (self.ub < other.ub)
+
     def __neg__(self):
         """[summary]
 
@@ -260,6 +476,22 @@ class Interval:
         """
         return Interval(self.lb - rhs, self.ub - rhs)
 
+    def __mul__(self, alpha):
+        """Scale both bounds by a scalar
+
+        Args:
+            alpha ([type]): scaling factor (non-negative)
+
+        Returns:
+            Interval: the scaled interval
+
+        Examples:
+            >>> a = Interval(3, 4)
+            >>> print(a * 2)
+            [6, 8]
+        """
+        return Interval(self.lb * alpha, self.ub * alpha)
+
     def overlaps(self, a) -> bool:
         """[summary]
 
@@ -276,6 +508,7 @@ class Interval:
             >>> a.overlaps(Interval(6, 9))
             False
         """
+        assert self.is_valid() and (isscalar(a) or a.is_valid())
         return not (self < a or a < self)
 
     def contains(self, a) -> bool:
@@ -324,6 +557,11 @@ class Interval:
     def intersection_with(self, other):
         """[summary]
 
+        Both intervals must be valid, which is asserted, and must overlap:
+        the result is built by the constructor, whose assertion fails for
+        disjoint operands. Use `classify_intersection` when they may be
+        disjoint.
+
         Args:
             other ([type]): [description]
 
@@ -342,8 +580,40 @@ class Interval:
         # `a` can be an Interval or int
         if isscalar(other):
             return other
+        assert self.is_valid() and other.is_valid()
         return Interval(max(self.lb, other.lb), min(self.ub, other.ub))
 
+    def classify_intersection(self, other):
+        """Intersection that tells touching apart from overlapping
+
+        `intersection_with` gives a zero-width interval when the intervals
+        merely touch; here that case is reported separately.
+
+        Args:
+            other (Interval): the other interval
+
+        Returns:
+            Tuple[Intersection, Any]: `(DISJOINT, None)`, `(TOUCHING, p)`
+                with the touch point `p`, or `(OVERLAPPING, r)` with the
+                common interval `r`
+
+        Examples:
+            >>> a = Interval(0, 5)
+            >>> a.classify_intersection(Interval(5, 10))
+            (<Intersection.TOUCHING: 1>, 5)
+            >>> kind, r = a.classify_intersection(Interval(3, 10))
+            >>> print(kind, r)
+            Intersection.OVERLAPPING [3, 5]
+            >>> a.classify_intersection(Interval(6, 10))
+            (<Intersection.DISJOINT: 0>, None)
+        """
+        lb, ub = max(self.lb, other.lb), min(self.ub, other.ub)
+        if lb > ub:
+            return Intersection.DISJOINT, None
+        if lb == ub:
+            return Intersection.TOUCHING, lb
+        return Intersection.OVERLAPPING, Interval(lb, ub)
+
     def min_dist_with(self, other):
         """[summary]
 
@@ -389,6 +659,54 @@ class Interval:
             self = other = self.intersection_with(other)
         return 0
 
+    def to_track_range(self, pitch: int, offset: int = 0):
+        """Inclusive range of track indices falling inside the interval
+
+        Track `k` is located at `offset + k * pitch`. Floor division is
+        used, so negative coordinates and offsets are handled correctly.
+        If no track falls inside, the first index exceeds the last one.
+
+        Args:
+            pitch (int): distance between neighbouring tracks
+            offset (int): location of track 0
+
+        Returns:
+            Tuple[int, int]: the first and the last track index
+
+        Examples:
+            >>> a = Interval(10, 20)
+            >>> a.to_track_range(5)
+            (2, 4)
+            >>> a.to_track_range(5, -3)
+            (3, 4)
+        """
+        assert pitch > 0
+        first = -((offset - self.lb) // pitch)
+        last = (self.ub - offset) // pitch
+        return first, last
+
+    def clamp_reported(self, value):
+        """Clamp a value into the interval and report whether it moved
+
+        Args:
+            value: the value to clamp
+
+        Returns:
+            Tuple: the clamped value and True if it differs from `value`
+
+        Examples:
+            >>> a = Interval(3, 8)
+            >>> a.clamp_reported(5)
+            (5, False)
+            >>> a.clamp_reported(10)
+            (8, True)
+        """
+        if value < self._lb:
+            return self._lb, True
+        if value > self._ub:
+            return self._ub, True
+        return value, False
+
     def enlarge_with(self, alpha):
         """[summary]
 
@@ -406,6 +724,12 @@ class Interval:
         return Interval(self._lb - alpha, self._ub + alpha)
 
 
+def _with_units(obj, unit: str) -> str:
+    if isscalar(obj):
+        return "{}{}".format(obj, unit)
+    return obj.display_units(unit)
+
+
 def hull(lhs, rhs):
     """[summary]
 
@@ -443,3 +767,147 @@ def enlarge(lhs, rhs):
         return lhs.enlarge_with(rhs)
     else:
         return Interval(lhs - rhs, lhs + rhs)
+
+
+def sort_intervals_by_lb(intervals):
+    """Sort intervals in place by lower bound (stable)
+
+    Args:
+        intervals (List[Interval]): the intervals
+
+    Examples:
+        >>> lst = [Interval(5, 6), Interval(1, 9), Interval(3, 4)]
+        >>> sort_intervals_by_lb(lst)
+        >>> print(*lst)
+        [1, 9] [3, 4] [5, 6]
+    """
+    intervals.sort(key=lambda a: a.lb)
+
+
+def merge_intervals(intervals):
+    """Coalesce overlapping or touching intervals into disjoint runs
+
+    Args:
+        intervals (List[Interval]): the intervals, in any order
+
+    Returns:
+        List[Interval]: the fewest intervals covering the same points,
+            sorted and separated by gaps of positive length
+
+    Examples:
+        >>> lst = [Interval(6, 8), Interval(1, 3), Interval(3, 5), Interval(2, 4)]
+        >>> print(*merge_intervals(lst))
+        [1, 5] [6, 8]
+    """
+    merged = []
+    for a in sorted(intervals, key=lambda a: a.lb):
+        if merged and not (merged[-1].ub < a.lb):
+            if merged[-1].ub < a.ub:
+                merged[-1] = Interval(merged[-1].lb, a.ub)
+        else:
+            merged.append(Interval(a.lb, a.ub))
+    return merged
+
+
+def interval_gaps(occupied, within):
+    """Free parts of an interval not covered by occupied intervals
+
+    The gaps share their endpoints with the neighbouring occupied runs, and
+    only gaps of positive length are reported. Occupied intervals reaching
+    outside `within` are clipped to it.
+
+    Args:
+        occupied (List[Interval]): the occupied intervals, in any order
+        within (Interval): the range to look for gaps in
+
+    Returns:
+        List[Interval]: the gaps, in increasing order
+
+    Examples:
+        >>> occupied = [Interval(4, 6), Interval(-3, 1), Interval(5, 7)]
+        >>> print(*interval_gaps(occupied, Interval(0, 10)))
+        [1, 4] [7, 10]
+    """
+    gaps = []
+    lb = within.lb
+    for a in merge_intervals(occupied):
+        if within.ub <= a.lb:
+            break
+        if lb < a.lb:
+            gaps.append(Interval(lb, a.lb))
+        lb = max(lb, a.ub)
+    if lb < within.ub:
+        gaps.append(Interval(lb, within.ub))
+    return gaps
+
+
+def union_centroid(intervals):
+    """Length-weighted center of the union of intervals
+
+    Overlapping intervals are merged first so that doubly covered parts
+    are only counted once.
+
+    Args:
+        intervals (List[Interval]): the intervals
+
+    Returns:
+        Optional[float]: the centroid, or None if the union has zero length
+
+    Examples:
+        >>> union_centroid([Interval(0, 2), Interval(4, 6)])
+        3.0
+        >>> union_centroid([Interval(0, 4), Interval(2, 4)])
+        2.0
+    """
+    total = 0
+    moment = 0
+    cur_lb = cur_ub = None
+    for a in sorted(intervals, key=lambda a: a.lb) + [None]:
+        if a is not None and cur_ub is not None and not (cur_ub < a.lb):
+            cur_ub = max(cur_ub, a.ub)
+            continue
+        if cur_ub is not None:
+            total += cur_ub - cur_lb
+            moment += (cur_ub - cur_lb) * (cur_lb + cur_ub)
+        if a is not None:
+            cur_lb, cur_ub = a.lb, a.ub
+    if total == 0:
+        return None
+    return moment / (2 * total)
+
+
+def weighted_quantile(positions, weights, q: float):
+    """Position at quantile `q` of a weighted distribution
+
+    This generalizes the weighted median (`q = 0.5`). The result is the
+    smallest position whose cumulative weight reaches `q` of the total;
+    `q = 0` and `q = 1` give the minimum and the maximum position.
+
+    Args:
+        positions ([type]): the positions
+        weights (List[float]): non-negative weights, one per position
+        q (float): the quantile, between 0 and 1
+
+    Returns:
+        [type]: the position at the quantile
+
+    Examples:
+        >>> weighted_quantile([1, 5, 3, 9], [1.0, 1.0, 5.0, 1.0], 0.5)
+        3
+        >>> weighted_quantile([1, 5, 3, 9], [1.0, 1.0, 5.0, 1.0], 1.0)
+        9
+    """
+    assert len(positions) == len(weights) > 0
+    assert 0 <= q <= 1
+    if q == 0:
+        return min(positions)
+    if q == 1:
+        return max(positions)
+    pairs = sorted(zip(positions, weights), key=lambda a: a[0])
+    target = q * sum(weights)
+    cumulative = 0.0
+    for pos, w in pairs:
+        cumulative += w
+        if cumulative >= target:
+            return pos
+    return pairs[-1][0]
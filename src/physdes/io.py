@@ -0,0 +1,67 @@
+import struct
+from typing import List
+
+from .interval import Interval
+from .recti import Rect
+
+_COUNT = struct.Struct("<I")
+_RECT = struct.Struct("<4i")
+
+
+def serialize_layer(rects, w):
+    """Write rectangles to a binary stream in a compact layer format
+
+    The format is a little-endian unsigned 32-bit count followed by, for
+    each rectangle, four signed 32-bit integers `xlb, xub, ylb, yub`. A
+    layer of `n` rectangles therefore takes exactly `4 + 16 * n` bytes.
+
+    Args:
+        rects (List[Rect]): rectangles with integer coordinates
+        w ([type]): writable binary stream
+
+    Examples:
+        >>> import io
+        >>> buf = io.BytesIO()
+        >>> serialize_layer([Rect(Interval(3, 4), Interval(5, 6))], buf)
+        >>> len(buf.getvalue())
+        20
+    """
+    w.write(_COUNT.pack(len(rects)))
+    for r in rects:
+        w.write(_RECT.pack(r.x.lb, r.x.ub, r.y.lb, r.y.ub))
+
+
+def _read_exact(r, size: int) -> bytes:
+    data = r.read(size)
+    if len(data) != size:
+        raise ValueError("truncated layer data")
+    return data
+
+
+def deserialize_layer(r) -> List[Rect]:
+    """Read rectangles written by `serialize_layer`
+
+    Args:
+        r ([type]): readable binary stream
+
+    Returns:
+        List[Rect]: the rectangles
+
+    Raises:
+        ValueError: if the stream ends before the announced rectangles
+
+    Examples:
+        >>> import io
+        >>> buf = io.BytesIO()
+        >>> serialize_layer([Rect(Interval(3, 4), Interval(5, 6))], buf)
+        >>> _ = buf.seek(0)
+        >>> for rect in deserialize_layer(buf):
+        ...     print(rect)
+        ([3, 4], [5, 6])
+    """
+    (count,) = _COUNT.unpack(_read_exact(r, _COUNT.size))
+    rects = []
+    for _ in range(count):
+        xlb, xub, ylb, yub = _RECT.unpack(_read_exact(r, _RECT.size))
+        rects.append(Rect(Interval(xlb, xub), Interval(ylb, yub)))
+    return rects
@@ -0,0 +1,126 @@
+from enum import Enum
+
+from .point import Orientation, Point, orientation
+
+
+class SegmentIntersection(Enum):
+    """How two line segments meet, see `LineSegment.intersect_with`"""
+
+    DISJOINT = 0
+    CROSSING = 1
+    TOUCHING = 2
+    OVERLAPPING = 3
+
+
+def _lex(p: Point):
+    return (p.x, p.y)
+
+
+class LineSegment:
+    """Straight segment between two points, in any direction
+
+    Unlike VSegment and HSegment, the segment need not be axis-aligned.
+
+    Examples:
+        >>> s = LineSegment(Point(0, 0), Point(4, 2))
+        >>> print(s)
+        (0, 0)--(4, 2)
+    """
+
+    __slots__ = ("_start", "_end")
+
+    def __init__(self, start: Point, end: Point):
+        """[summary]
+
+        Args:
+            start (Point): the first endpoint
+            end (Point): the second endpoint
+        """
+        self._start = start
+        self._end = end
+
+    def __str__(self):
+        return "{self.start}--{self.end}".format(self=self)
+
+    def __eq__(self, rhs) -> bool:
+        return (self._start, self._end) == (rhs.start, rhs.end)
+
+    @property
+    def start(self) -> Point:
+        return self._start
+
+    @property
+    def end(self) -> Point:
+        return self._end
+
+    def contains_point(self, q: Point) -> bool:
+        """Whether a point lies on the segment (endpoints included)
+
+        Examples:
+            >>> s = LineSegment(Point(0, 0), Point(4, 2))
+            >>> s.contains_point(Point(2, 1)), s.contains_point(Point(6, 3))
+            (True, False)
+        """
+        a, b = self._start, self._end
+        return (
+            orientation(a, b, q) == Orientation.COLLINEAR
+            and min(a.x, b.x) <= q.x <= max(a.x, b.x)
+            and min(a.y, b.y) <= q.y <= max(a.y, b.y)
+        )
+
+    def intersect_with(self, other):
+        """Common part of two segments
+
+        A proper crossing, where each segment passes through the interior of
+        the other, is told apart from touching, where an endpoint of one
+        lies on the other (a T-junction or a shared endpoint). Collinear
+        segments may also share a piece of positive length.
+
+        Args:
+            other (LineSegment): the other segment
+
+        Returns:
+            Tuple[SegmentIntersection, Any]: `(DISJOINT, None)`,
+                `(CROSSING, p)` or `(TOUCHING, p)` with the common point
+                `p`, or `(OVERLAPPING, s)` with the common segment `s`
+
+        Examples:
+            >>> s = LineSegment(Point(0, 0), Point(4, 4))
+            >>> kind, p = s.intersect_with(LineSegment(Point(0, 4), Point(4, 0)))
+            >>> print(kind, p)
+            SegmentIntersection.CROSSING (2.0, 2.0)
+            >>> kind, t = s.intersect_with(LineSegment(Point(2, 2), Point(6, 6)))
+            >>> print(kind, t)
+            SegmentIntersection.OVERLAPPING (2, 2)--(4, 4)
+        """
+        a, b, c, d = self._start, self._end, other.start, other.end
+        # A zero-length segment is collinear with everything, so test it as
+        # a point instead
+        if a == b or c == d:
+            p, s = (a, other) if a == b else (c, self)
+            if s.contains_point(p):
+                return SegmentIntersection.TOUCHING, p
+            return SegmentIntersection.DISJOINT, None
+
+        o1, o2 = orientation(a, b, c), orientation(a, b, d)
+        o3, o4 = orientation(c, d, a), orientation(c, d, b)
+        collinear = Orientation.COLLINEAR
+
+        if o1 == o2 == collinear:
+            lo = max(min(a, b, key=_lex), min(c, d, key=_lex), key=_lex)
+            hi = min(max(a, b, key=_lex), max(c, d, key=_lex), key=_lex)
+            if _lex(hi) < _lex(lo):
+                return SegmentIntersection.DISJOINT, None
+            if hi == lo:
+                return SegmentIntersection.TOUCHING, lo
+            return SegmentIntersection.OVERLAPPING, LineSegment(lo, hi)
+
+        if o1 != o2 and o3 != o4 and collinear not in (o1, o2, o3, o4):
+            u, v = b - a, d - c
+            t = (c - a).cross(v) / u.cross(v)
+            return SegmentIntersection.CROSSING, Point(a.x + t * u.x, a.y + t * u.y)
+
+        for p, s in ((c, self), (d, self), (a, other), (b, other)):
+            if s.contains_point(p):
+                return SegmentIntersection.TOUCHING, p
+        return SegmentIntersection.DISJOINT, None
@@ -0,0 +1,386 @@
+from typing import Sequence
+
+from .interval import Interval
+from .line_segment import LineSegment
+from .merge_obj import MergeObj
+from .point import Point
+from .polygon import Polygon
+
+
+class ManhattanArc:
+    """Set of points at a fixed Manhattan distance from a center
+
+    The locus is the boundary of a diamond (a square rotated by 45 degrees).
+    """
+
+    __slots__ = ("_center", "_radius")
+
+    def __init__(self, center: Point, radius):
+        """[summary]
+
+        Args:
+            center (Point): the center
+            radius ([type]): the Manhattan distance
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> print(a)
+            {(3, 4), 2}
+        """
+        self._center = center
+        self._radius = radius
+
+    def __str__(self):
+        """[summary]
+
+        Returns:
+            [type]: [description]
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> print(a)
+            {(3, 4), 2}
+        """
+        return "{{{self.center}, {self.radius}}}".format(self=self)
+
+    @property
+    def center(self) -> Point:
+        """Center of the arc
+
+        Returns:
+            Point: the center
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> print(a.center)
+            (3, 4)
+        """
+        return self._center
+
+    @property
+    def radius(self):
+        """Radius of the arc
+
+        Returns:
+            [type]: the Manhattan distance from the center
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> a.radius
+            2
+        """
+        return self._radius
+
+    def with_radius(self, r):
+        """The arc with the same center and another radius
+
+        Args:
+            r ([type]): the new radius (clamped at zero)
+
+        Returns:
+            ManhattanArc: the new arc
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> print(a.with_radius(7))
+            {(3, 4), 7}
+        """
+        return ManhattanArc(self._center, max(r, 0))
+
+    def grow(self, delta):
+        """The arc with the radius increased by `delta`
+
+        A negative `delta` shrinks the arc; the radius is clamped at zero.
+
+        Args:
+            delta ([type]): change of the radius
+
+        Returns:
+            ManhattanArc: the new arc
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> print(a.grow(-5))
+            {(3, 4), 0}
+        """
+        return self.with_radius(self._radius + delta)
+
+    def is_empty(self) -> bool:
+        """Whether the arc has shrunk away
+
+        An arc whose radius is zero or negative no longer encloses any
+        region and is regarded as empty by merge-region refinement.
+
+        Returns:
+            bool: True if the radius is not positive
+
+        Examples:
+            >>> ManhattanArc(Point(3, 4), 2).is_empty()
+            False
+        """
+        return self._radius <= 0
+
+    def contains_point(self, p: Point) -> bool:
+        """Whether a point lies on the arc
+
+        Args:
+            p (Point): the query point
+
+        Returns:
+            bool: True if `p` is exactly `radius` away from the center
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> a.contains_point(Point(4, 5))
+            True
+            >>> a.contains_point(Point(3, 4))
+            False
+        """
+        return self._center.min_dist_with(p) == self._radius
+
+    def to_polygon(self) -> Polygon:
+        """The diamond as a polygon
+
+        Returns:
+            Polygon: the four tips in counter-clockwise order, starting from
+                the bottom one
+
+        Examples:
+            >>> a = ManhattanArc(Point(3, 4), 2)
+            >>> for p in a.to_polygon().vertices():
+            ...     print(p)
+            (3, 2)
+            (5, 4)
+            (3, 6)
+            (1, 4)
+        """
+        cx, cy, r = self._center.x, self._center.y, self._radius
+        return Polygon(
+            [Point(cx, cy - r), Point(cx + r, cy), Point(cx, cy + r), Point(cx - r, cy)]
+        )
+
+
+class ManhattanBall:
+    """Set of points within a Manhattan distance of a center
+
+    The region is a filled diamond (a square rotated by 45 degrees).
+    """
+
+    __slots__ = ("_center", "_radius")
+
+    def __init__(self, center: Point, radius):
+        """[summary]
+
+        Args:
+            center (Point): the center
+            radius ([type]): the largest Manhattan distance
+
+        Examples:
+            >>> a = ManhattanBall(Point(3, 4), 2)
+            >>> print(a)
+            <(3, 4), 2>
+        """
+        self._center = center
+        self._radius = radius
+
+    def __str__(self):
+        """[summary]
+
+        Returns:
+            [type]: [description]
+
+        Examples:
+            >>> a = ManhattanBall(Point(3, 4), 2)
+            >>> print(a)
+            <(3, 4), 2>
+        """
+        return "<{self.center}, {self.radius}>".format(self=self)
+
+    @property
+    def center(self) -> Point:
+        """Center of the ball
+
+        Returns:
+            Point: the center
+        """
+        return self._center
+
+    @property
+    def radius(self):
+        """Radius of the ball
+
+        Returns:
+            [type]: the largest Manhattan distance from the center
+        """
+        return self._radius
+
+    def contains_point(self, p: Point) -> bool:
+        """Whether a point lies in the ball (boundary included)
+
+        Args:
+            p (Point): the query point
+
+        Returns:
+            bool: True if `p` is at most `radius` away from the center
+
+        Examples:
+            >>> a = ManhattanBall(Point(3, 4), 2)
+            >>> a.contains_point(Point(3, 4))
+            True
+            >>> a.contains_point(Point(5, 5))
+            False
+        """
+        return self._center.min_dist_with(p) <= self._radius
+
+    def overlaps(self, other) -> bool:
+        """Whether two balls share at least one point
+
+        Args:
+            other (ManhattanBall): the other ball
+
+        Returns:
+            bool: True if the sum of the radii reaches the distance between
+                the centers
+
+        Examples:
+            >>> a = ManhattanBall(Point(0, 0), 2)
+            >>> a.overlaps(ManhattanBall(Point(3, 1), 2))
+            True
+            >>> a.overlaps(ManhattanBall(Point(3, 2), 2))
+            False
+        """
+        return self._center.min_dist_with(other.center) <= self._radius + other.radius
+
+    def intersection_with(self, other):
+        """Common region of two balls
+
+        The region is a square rotated by 45 degrees. Its edges are
+        diagonal, so it is returned as a general Polygon rather than an
+        RPolygon. When the balls merely touch, the region degenerates to a
+        diagonal segment or a single point, which is returned as such.
+
+        Args:
+            other (ManhattanBall): the other ball
+
+        Returns:
+            Union[Polygon, LineSegment, Point, None]: the square with its
+                corners in counter-clockwise order, the segment or the point
+                where the balls touch, or None if the balls are disjoint
+
+        Examples:
+            >>> a = ManhattanBall(Point(0, 0), 2)
+            >>> for p in a.intersection_with(ManhattanBall(Point(2, 0), 2)).vertices():
+            ...     print(p)
+            (0.0, 0.0)
+            (1.0, -1.0)
+            (2.0, 0.0)
+            (1.0, 1.0)
+            >>> print(a.intersection_with(ManhattanBall(Point(4, 0), 2)))
+            (2.0, 0.0)
+        """
+        trr = merging_region(self, other)
+        if trr is None:
+            return None
+        u, v = trr.x, trr.y
+        corners = [
+            Point((a + b) / 2, (a - b) / 2)
+            for a, b in [(u.lb, v.lb), (u.lb, v.ub), (u.ub, v.ub), (u.ub, v.lb)]
+        ]
+        if u.lb == u.ub and v.lb == v.ub:
+            return corners[0]
+        if u.lb == u.ub or v.lb == v.ub:
+            return LineSegment(corners[0], corners[2])
+        return Polygon(corners)
+
+    def to_merge_obj(self) -> MergeObj:
+        """The ball in the rotated frame (u = x + y, v = x - y)
+
+        In the rotated frame the diamond becomes an axis-aligned square.
+
+        Returns:
+            MergeObj: the square, with an Interval in each coordinate
+
+        Examples:
+            >>> a = ManhattanBall(Point(3, 4), 2)
+            >>> print(a.to_merge_obj())
+            /[5, 9], [-3, 1]/
+        """
+        c = self._center
+        return MergeObj(c.x + c.y, c.x - c.y).enlarge_with(self._radius)
+
+
+def merging_region(lhs: ManhattanBall, rhs: ManhattanBall):
+    """Intersection of two Manhattan balls in the rotated frame
+
+    This is the core step of deferred-merge embedding (DME). The 45-degree
+    transform u = x + y, v = x - y maps the L1 metric to the L-infinity
+    metric, so each ball becomes an axis-aligned square and their
+    intersection is an axis-aligned rectangle in (u, v). A point (u, v)
+    maps back to x = (u + v) / 2, y = (u - v) / 2.
+
+    When the balls merely touch, one (segment) or both (point) of the
+    resulting intervals have zero length.
+
+    Args:
+        lhs (ManhattanBall): [description]
+        rhs (ManhattanBall): [description]
+
+    Returns:
+        Optional[MergeObj]: the region as a pair of intervals in (u, v), or
+            None if the balls are disjoint
+
+    Examples:
+        >>> a = ManhattanBall(Point(0, 0), 2)
+        >>> print(merging_region(a, ManhattanBall(Point(3, 1), 2)))
+        /[2, 2], [0, 2]/
+        >>> print(merging_region(a, ManhattanBall(Point(5, 0), 2)))
+        None
+    """
+    if not lhs.overlaps(rhs):
+        return None
+    return lhs.to_merge_obj().intersection_with(rhs.to_merge_obj())
+
+
+def multi_merging_region(balls: Sequence[ManhattanBall]):
+    """Common region of several Manhattan balls, or the best compromise
+
+    This generalizes `merging_region` to a node merging k children, each
+    ball being the locations reachable with the wire length budgeted for
+    that child. In the rotated frame the balls are axis-aligned squares,
+    so the common region is the intersection of one interval per axis.
+
+    If the balls have no common point, some child must get a longer wire
+    than budgeted. The overshoot `t` of a location is the largest amount by
+    which its distance to a center exceeds the radius; the smallest
+    achievable `t` is where the squares grown by `t` start to intersect.
+    A point of that intersection is returned (inserting detours to balance
+    the delays is left to the caller).
+
+    Args:
+        balls (Sequence[ManhattanBall]): the grown children, at least one
+
+    Returns:
+        Tuple[MergeObj, [type]]: the region in (u, v) and 0 if the balls
+            intersect; otherwise a point in (u, v) minimizing the overshoot,
+            and that overshoot
+
+    Examples:
+        >>> balls = [ManhattanBall(Point(0, 0), 2), ManhattanBall(Point(2, 0), 2)]
+        >>> region, t = multi_merging_region(balls)
+        >>> print(region)
+        /[0, 2], [0, 2]/
+        >>> balls.append(ManhattanBall(Point(0, 6), 2))
+        >>> point, t = multi_merging_region(balls)
+        >>> print(point)
+        /3.0, -2.0/
+        >>> t
+        2.0
+    """
+    boxes = [b.to_merge_obj() for b in balls]
+    ulb, uub = max(r.x.lb for r in boxes), min(r.x.ub for r in boxes)
+    vlb, vub = max(r.y.lb for r in boxes), min(r.y.ub for r in boxes)
+    if ulb <= uub and vlb <= vub:
+        return MergeObj(Interval(ulb, uub), Interval(vlb, vub)), 0
+    # the midpoints lie in the intervals grown by t in both axes
+    t = max(ulb - uub, vlb - vub) / 2
+    return MergeObj((ulb + uub) / 2, (vlb + vub) / 2), t
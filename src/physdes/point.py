@@ -1,19 +1,26 @@
-from .generic import contain, intersection, min_dist, overlap
-from .interval import hull
+import math
+from enum import Enum
+
+from .generic import center, contain, intersection, min_dist, overlap
+from .interval import _with_units, enlarge, hull
 from .vector2 import Vector2
 
 
 class Point:
     __slots__ = ("x", "y")
 
-    def __init__(self, x, y):
+    def __init__(self, x=0, y=0):
         """[summary]
 
+        The default is the origin.
+
         Args:
             x ([type]): [description]
             y ([type]): [description]
 
         Examples:
+            >>> print(Point())
+            (0, 0)
             >>> a = Point(3, 4)
             >>> print(a)
             (3, 4)
@@ -40,6 +47,22 @@ class Point:
         """
         return "({self.x}, {self.y})".format(self=self)
 
+    def display_units(self, unit: str) -> str:
+        """String form with a unit suffix on each coordinate
+
+        Args:
+            unit (str): the unit, e.g. "nm"
+
+        Returns:
+            str: the annotated string
+
+        Examples:
+            >>> a = Point(3, 4)
+            >>> a.display_units("nm")
+            '(3nm, 4nm)'
+        """
+        return "({}, {})".format(_with_units(self.x, unit), _with_units(self.y, unit))
+
     def copy(self):
         """[summary]
 
@@ -58,6 +81,115 @@ class Point:
         """
         return Point(self.x, self.y)
 
+    @classmethod
+    def origin(cls):
+        """The point (0, 0)
+
+        Examples:
+            >>> print(Point.origin())
+            (0, 0)
+        """
+        return cls(0, 0)
+
+    @classmethod
+    def from_tuple(cls, t):
+        """Construct from an `(x, y)` pair
+
+        Examples:
+            >>> print(Point.from_tuple((1, 2)))
+            (1, 2)
+        """
+        x, y = t
+        return cls(x, y)
+
+    def to_tuple(self):
+        """The coordinates as an `(x, y)` pair
+
+        Examples:
+            >>> Point(1, 2).to_tuple()
+            (1, 2)
+        """
+        return (self.x, self.y)
+
+    def as_vector(self) -> Vector2:
+        """The position vector of the point (its displacement from the origin)
+
+        Examples:
+            >>> print(Point(1, 2).as_vector())
+            <1, 2>
+        """
+        return Vector2(self.x, self.y)
+
+    def displacement_to(self, other) -> Vector2:
+        """The vector leading from this point to `other`
+
+        Same as `other - self`, spelled so that the direction is explicit.
+
+        Examples:
+            >>> print(Point(1, 2).displacement_to(Point(4, 0)))
+            <3, -2>
+        """
+        return other - self
+
+    def displacement_from(self, other) -> Vector2:
+        """The vector leading from `other` to this point
+
+        Same as `self - other`; the opposite of `displacement_to`.
+
+        Examples:
+            >>> print(Point(1, 2).displacement_from(Point(4, 0)))
+            <-3, 2>
+        """
+        return self - other
+
+    def midpoint(self, other):
+        """The point halfway to `other`
+
+        True division is used, as in `center`, so integer coordinates give
+        float results.
+
+        Examples:
+            >>> print(Point(0, 0).midpoint(Point(4, 6)))
+            (2.0, 3.0)
+        """
+        return Point((self.x + other.x) / 2, (self.y + other.y) / 2)
+
+    def __neg__(self):
+        """The point reflected through the origin (see `rotate_180`)
+
+        Examples:
+            >>> print(-Point(3, -4))
+            (-3, 4)
+        """
+        return self.rotate_180()
+
+    def cmp_lexicographic(self, other) -> int:
+        """Three-way comparison by x, then by y
+
+        Usable with `functools.cmp_to_key` for sweep-line orderings.
+
+        Returns:
+            int: -1, 0 or 1
+
+        Examples:
+            >>> Point(1, 5).cmp_lexicographic(Point(1, 2))
+            1
+        """
+        a, b = (self.x, self.y), (other.x, other.y)
+        return (a > b) - (a < b)
+
+    def cmp_by_x(self, other) -> int:
+        """Three-way comparison by x only
+
+        Returns:
+            int: -1, 0 or 1
+
+        Examples:
+            >>> Point(1, 5).cmp_by_x(Point(1, 2))
+            0
+        """
+        return (self.x > other.x) - (self.x < other.x)
+
     def __lt__(self, rhs) -> bool:
         """[summary]
 
@@ -223,6 +355,40 @@ class Point:
         else:
             return Point(self.x - rhs, self.y - rhs)
 
+    def translate(self, v: Vector2):
+        """Move the object by a displacement
+
+        Args:
+            v (Vector2): displacement
+
+        Returns:
+            [type]: the translated object, of the same type as `self`
+
+        Examples:
+            >>> a = Point(3, 4)
+            >>> print(a.translate(Vector2(2, -3)))
+            (5, 1)
+        """
+        Self = type(self)
+        return Self(self.x + v.x, self.y + v.y)
+
+    def scale(self, factor):
+        """Scale the object about the origin
+
+        Args:
+            factor ([type]): scaling factor (non-negative, so that interval
+                coordinates stay valid)
+
+        Returns:
+            [type]: the scaled object, of the same type as `self`
+
+        Examples:
+            >>> print(Point(3, 4).scale(2))
+            (6, 8)
+        """
+        Self = type(self)
+        return Self(self.x * factor, self.y * factor)
+
     def flip(self):
         """[summary]
 
@@ -239,6 +405,125 @@ class Point:
         """
         return Point(self.y, self.x)
 
+    def rotate_90_ccw(self):
+        """Rotate by 90 degrees counter-clockwise about the origin
+
+        Like the reflections, the rotations keep the type of the object;
+        the segments override them, as a rotated VSegment is horizontal.
+        Together with `reflect_x` and `reflect_y` they form the transform
+        protocol, see `generic.apply_orientation`.
+
+        Returns:
+            Point: `(-y, x)`
+
+        Examples:
+            >>> a = Point(3, 4)
+            >>> print(a.rotate_90_ccw())
+            (-4, 3)
+        """
+        Self = type(self)
+        return Self(-self.y, self.x)
+
+    def rotate_90_cw(self):
+        """Rotate by 90 degrees clockwise about the origin
+
+        Returns:
+            Point: `(y, -x)`
+
+        Examples:
+            >>> a = Point(3, 4)
+            >>> print(a.rotate_90_cw())
+            (4, -3)
+        """
+        Self = type(self)
+        return Self(self.y, -self.x)
+
+    def rotate_180(self):
+        """Rotate by 180 degrees about the origin
+
+        Returns:
+            Point: `(-x, -y)`
+
+        Examples:
+            >>> a = Point(3, 4)
+            >>> print(a.rotate_180())
+            (-3, -4)
+        """
+        Self = type(self)
+        return Self(-self.x, -self.y)
+
+    def reflect_x(self):
+        """Mirror across the x-axis, keeping the type of the object
+
+        Returns:
+            Point: `(x, -y)`
+
+        Examples:
+            >>> print(Point(3, 4).reflect_x())
+            (3, -4)
+        """
+        Self = type(self)
+        return Self(self.x, -self.y)
+
+    def reflect_y(self):
+        """Mirror across the y-axis, keeping the type of the object
+
+        Returns:
+            Point: `(-x, y)`
+
+        Examples:
+            >>> print(Point(3, 4).reflect_y())
+            (-3, 4)
+        """
+        Self = type(self)
+        return Self(-self.x, self.y)
+
+    def reflect_across_vertical(self, x0):
+        """Mirror across the vertical line x = `x0`
+
+        Args:
+            x0 ([type]): position of the mirror line
+
+        Returns:
+            Point: `(2 * x0 - x, y)`
+
+        Examples:
+            >>> print(Point(3, 4).reflect_across_vertical(5))
+            (7, 4)
+        """
+        Self = type(self)
+        return Self(-(self.x - x0) + x0, self.y)  # also for an interval x
+
+    def reflect_across_horizontal(self, y0):
+        """Mirror across the horizontal line y = `y0`
+
+        Args:
+            y0 ([type]): position of the mirror line
+
+        Returns:
+            Point: `(x, 2 * y0 - y)`
+
+        Examples:
+            >>> print(Point(3, 4).reflect_across_horizontal(1))
+            (3, -2)
+        """
+        Self = type(self)
+        return Self(self.x, -(self.y - y0) + y0)  # also for an interval y
+
+    def center(self):
+        """Center of the object
+
+        Returns:
+            Point: the point made of the centers of both coordinates
+
+        Examples:
+            >>> from physdes.interval import Interval
+            >>> a = Point(Interval(3, 8), 4)
+            >>> print(a.center())
+            (5.5, 4)
+        """
+        return Point(center(self.x), center(self.y))
+
     def overlaps(self, other) -> bool:
         """[summary]
 
@@ -273,6 +558,45 @@ class Point:
         Self = type(self)
         return Self(hull(self.x, other.x), hull(self.y, other.y))
 
+    def enlarge_to_rect(self, alpha):
+        """The rectangle grown from this point by `alpha` on every side
+
+        This is the usual way to turn a pin into a landing pad.
+
+        Args:
+            alpha ([type]): the margin
+
+        Returns:
+            Rect: the rectangle
+
+        Examples:
+            >>> print(Point(0, 0).enlarge_to_rect(2))
+            ([-2, 2], [-2, 2])
+        """
+        from .recti import Rect
+
+        return Rect(enlarge(self.x, alpha), enlarge(self.y, alpha))
+
+    def hull_rect_with(self, other):
+        """The smallest rectangle containing both objects
+
+        Unlike `hull_with`, which keeps the type of `self`, the result is
+        always a Rect.
+
+        Args:
+            other ([type]): a Point, segment or Rect
+
+        Returns:
+            Rect: the bounding rectangle
+
+        Examples:
+            >>> print(Point(3, 1).hull_rect_with(Point(0, 4)))
+            ([0, 3], [1, 4])
+        """
+        from .recti import Rect
+
+        return Rect(hull(self.x, other.x), hull(self.y, other.y))
+
     def intersection_with(self, other):
         """[summary]
 
@@ -287,12 +611,392 @@ class Point:
                     intersection(self.y, other.y))
 
     def min_dist_with(self, other):
-        """[summary]
+        """Manhattan distance to another object
+
+        Each coordinate may be a scalar or an Interval, so the distance is
+        symmetric between points, rectangles and segments:
+        `p.min_dist_with(r) == r.min_dist_with(p)`.
 
         Args:
-            other ([type]): [description]
+            other ([type]): a Point, Rect, VSegment or HSegment
 
         Returns:
-            [type]: [description]
+            [type]: the distance, 0 if the objects overlap
+
+        Examples:
+            >>> from physdes.interval import Interval
+            >>> r = Point(Interval(0, 4), Interval(0, 4))  # Rect
+            >>> Point(6, -1).min_dist_with(r), r.min_dist_with(Point(6, -1))
+            (3, 3)
         """
         return min_dist(self.x, other.x) + min_dist(self.y, other.y)
+
+    def manhattan_distance(self, other):
+        """Manhattan (L1) distance to another point
+
+        The same as `min_dist_with`, under a more discoverable name.
+
+        Args:
+            other (Point): the other point
+
+        Returns:
+            [type]: `|dx| + |dy|`
+
+        Examples:
+            >>> Point(1, 2).manhattan_distance(Point(4, -2))
+            7
+        """
+        return self.min_dist_with(other)
+
+    def euclidean_distance_squared(self, other):
+        """Squared Euclidean distance to another point
+
+        Exact for integer coordinates; use it instead of
+        `euclidean_distance` when only comparing distances.
+
+        Args:
+            other (Point): the other point
+
+        Returns:
+            [type]: `dx * dx + dy * dy`, in the coordinate type
+
+        Examples:
+            >>> Point(0, 0).euclidean_distance_squared(Point(3, 4))
+            25
+        """
+        dx, dy = self.x - other.x, self.y - other.y
+        return dx * dx + dy * dy
+
+    def euclidean_distance(self, other) -> float:
+        """Euclidean distance to another point
+
+        The default metric of this library remains Manhattan (see
+        `min_dist_with`); this is meant for comparison and reporting.
+
+        Args:
+            other (Point): the other point
+
+        Returns:
+            float: `sqrt(dx * dx + dy * dy)`
+
+        Examples:
+            >>> Point(0, 0).euclidean_distance(Point(3, 4))
+            5.0
+        """
+        return math.sqrt(self.euclidean_distance_squared(other))
+
+
+class Orientation(Enum):
+    """Turn direction of three points, see `orientation`"""
+
+    COUNTER_CLOCKWISE = 1
+    CLOCKWISE = -1
+    COLLINEAR = 0
+
+
+def orientation(a, b, c) -> Orientation:
+    """Whether going from `a` through `b` to `c` turns left or right
+
+    This is the sign of the cross product `(b - a) x (c - a)`, the basic
+    predicate of convex hull, point-in-polygon and segment intersection
+    tests. It is exact for integer coordinates.
+
+    Args:
+        a (Point): the first point
+        b (Point): the second point
+        c (Point): the third point
+
+    Returns:
+        Orientation: COUNTER_CLOCKWISE for a left turn, CLOCKWISE for a
+            right turn, COLLINEAR if the points are on a line
+
+    Examples:
+        >>> orientation(Point(0, 0), Point(2, 0), Point(1, 1))
+        <Orientation.COUNTER_CLOCKWISE: 1>
+        >>> orientation(Point(0, 0), Point(2, 0), Point(5, 0))
+        <Orientation.COLLINEAR: 0>
+    """
+    d = (b - a).cross(c - a)
+    return Orientation((d > 0) - (d < 0))
+
+
+def grid_points(region, step: int):
+    """Lattice points inside a region, spaced by `step`
+
+    The points start at the lower-left corner and are generated in
+    row-major order (x varies fastest).
+
+    Args:
+        region ([type]): rectangle with integer bounds
+        step (int): spacing between neighbouring points
+
+    Yields:
+        Point: the next lattice point
+
+    Examples:
+        >>> from physdes.recti import Rect
+        >>> from physdes.interval import Interval
+        >>> r = Rect(Interval(0, 5), Interval(0, 3))
+        >>> for p in grid_points(r, 3):
+        ...     print(p)
+        (0, 0)
+        (3, 0)
+        (0, 3)
+        (3, 3)
+    """
+    assert step > 0
+    for y in range(region.y.lb, region.y.ub + 1, step):
+        for x in range(region.x.lb, region.x.ub + 1, step):
+            yield Point(x, y)
+
+
+def hpwl(pins):
+    """Half-perimeter wirelength of a net
+
+    Args:
+        pins (List[Point]): the pin locations
+
+    Returns:
+        [type]: `(max_x - min_x) + (max_y - min_y)`, 0 for fewer than two
+            pins
+
+    Examples:
+        >>> hpwl([Point(0, 0), Point(3, 5), Point(1, 2)])
+        8
+    """
+    if len(pins) < 2:
+        return 0
+    xs = [p.x for p in pins]
+    ys = [p.y for p in pins]
+    return (max(xs) - min(xs)) + (max(ys) - min(ys))
+
+
+def min_enclosing_square(points):
+    """Smallest axis-aligned square containing all points
+
+    The square is centered on the bounding box of the points. When the
+    bounding box must be extended by an odd integer amount, the extra unit
+    goes to the upper side.
+
+    Args:
+        points (List[Point]): the points
+
+    Returns:
+        Optional[Rect]: the square, or None if `points` is empty
+
+    Examples:
+        >>> pts = [Point(0, 0), Point(3, 5), Point(1, 2)]
+        >>> print(min_enclosing_square(pts))
+        ([-1, 4], [0, 5])
+    """
+    from .interval import Interval
+    from .recti import Rect
+
+    if not points:
+        return None
+    xlb = min(p.x for p in points)
+    xub = max(p.x for p in points)
+    ylb = min(p.y for p in points)
+    yub = max(p.y for p in points)
+    side = max(xub - xlb, yub - ylb)
+    xlb -= (side - (xub - xlb)) // 2
+    ylb -= (side - (yub - ylb)) // 2
+    return Rect(Interval(xlb, xlb + side), Interval(ylb, ylb + side))
+
+
+def translate_points(xs, ys, dx, dy):
+    """Translate points stored as parallel coordinate arrays, in place
+
+    This struct-of-arrays form avoids creating a `Point` per element and
+    works on anything supporting item assignment (lists, numpy arrays).
+
+    Args:
+        xs: the x-coordinates
+        ys: the y-coordinates, of the same length as `xs`
+        dx ([type]): the displacement in x
+        dy ([type]): the displacement in y
+
+    Examples:
+        >>> xs, ys = [1, 2, 3], [4, 5, 6]
+        >>> translate_points(xs, ys, 10, -1)
+        >>> xs, ys
+        ([11, 12, 13], [3, 4, 5])
+    """
+    assert len(xs) == len(ys)
+    for i in range(len(xs)):
+        xs[i] += dx
+        ys[i] += dy
+
+
+def k_nearest_to(point, candidates, k: int):
+    """The `k` candidates closest to `point` in the Manhattan metric
+
+    Args:
+        point (Point): the query point
+        candidates (List[Point]): the candidates
+        k (int): the number of neighbours wanted
+
+    Returns:
+        List[Point]: up to `k` candidates by ascending distance; equally
+            distant candidates keep their original order
+
+    Examples:
+        >>> cands = [Point(5, 5), Point(1, 0), Point(0, 2), Point(-1, 0)]
+        >>> for p in k_nearest_to(Point(0, 0), cands, 3):
+        ...     print(p)
+        (1, 0)
+        (-1, 0)
+        (0, 2)
+    """
+    order = sorted(
+        range(len(candidates)), key=lambda i: (point.min_dist_with(candidates[i]), i)
+    )
+    return [candidates[i] for i in order[: max(k, 0)]]
+
+
+def nearest_point_with_dist(point, candidates):
+    """The candidate closest to `point`, with its index and distance
+
+    Args:
+        point (Point): the query point
+        candidates (List[Point]): the candidates
+
+    Returns:
+        Optional[Tuple[int, Point, Any]]: the index, the candidate and its
+            Manhattan distance, or None if there are no candidates. Among
+            equally distant candidates the first one is returned.
+
+    Examples:
+        >>> cands = [Point(5, 5), Point(1, 2), Point(0, 3)]
+        >>> i, p, d = nearest_point_with_dist(Point(0, 0), cands)
+        >>> print(i, p, d)
+        1 (1, 2) 3
+    """
+    best = None
+    for i, p in enumerate(candidates):
+        d = point.min_dist_with(p)
+        if best is None or d < best[2]:
+            best = (i, p, d)
+    return best
+
+
+def nearest_point_to(point, candidates):
+    """The candidate closest to `point` in the Manhattan metric
+
+    Args:
+        point (Point): the query point
+        candidates (List[Point]): the candidates
+
+    Returns:
+        Optional[Point]: the first closest candidate, or None if empty
+
+    Examples:
+        >>> print(nearest_point_to(Point(0, 0), [Point(5, 5), Point(1, 2)]))
+        (1, 2)
+    """
+    best = nearest_point_with_dist(point, candidates)
+    return best[1] if best is not None else None
+
+
+class KdTree:
+    """2-D kd-tree for nearest-neighbour queries in the Manhattan metric
+
+    The points are split alternately by x and by y at the median. A subtree
+    on the far side of a splitting line is skipped when the distance to the
+    line already exceeds the best distance found, which is a valid lower
+    bound for the Manhattan distance.
+
+    Examples:
+        >>> T = KdTree.build([Point(0, 0), Point(5, 1), Point(2, 7)])
+        >>> T.nearest(Point(4, 4))
+        (1, 4)
+        >>> T.within_radius(Point(1, 1), 6)
+        [0, 1]
+    """
+
+    __slots__ = ("_points", "_nodes", "_root")
+
+    def __init__(self, points):
+        self._points = list(points)
+        self._nodes = []  # (index, axis, left, right)
+        self._root = self._build(list(range(len(self._points))), 0)
+
+    @classmethod
+    def build(cls, points):
+        """Build the tree over a list of points
+
+        Args:
+            points (List[Point]): the points, referred to by position
+
+        Returns:
+            KdTree: the tree
+        """
+        return cls(points)
+
+    def __len__(self) -> int:
+        return len(self._points)
+
+    def _coord(self, i: int, axis: int):
+        p = self._points[i]
+        return p.x if axis == 0 else p.y
+
+    def _build(self, ids, axis: int):
+        if not ids:
+            return None
+        ids.sort(key=lambda i: (self._coord(i, axis), i))
+        mid = len(ids) // 2
+        node = len(self._nodes)
+        self._nodes.append(None)
+        left = self._build(ids[:mid], 1 - axis)
+        right = self._build(ids[mid + 1 :], 1 - axis)
+        self._nodes[node] = (ids[mid], axis, left, right)
+        return node
+
+    def _visit(self, query, bound):
+        """Yield (distance, index) of the points whose subtree may be within
+        `bound()`, which is re-evaluated as the search progresses"""
+        stack = [self._root] if self._root is not None else []
+        while stack:
+            node = stack.pop()
+            if node is None:
+                continue
+            i, axis, left, right = self._nodes[node]
+            yield query.min_dist_with(self._points[i]), i
+            diff = (query.x if axis == 0 else query.y) - self._coord(i, axis)
+            near, far = (left, right) if diff < 0 else (right, left)
+            if abs(diff) <= bound():
+                stack.append(far)
+            stack.append(near)
+
+    def nearest(self, query):
+        """The point closest to `query`
+
+        Args:
+            query (Point): the query point
+
+        Returns:
+            Optional[Tuple[int, Any]]: the index of the nearest point (the
+                smallest one among equally distant points) and its distance,
+                or None if the tree is empty
+        """
+        best = None
+
+        def bound():
+            return best[0] if best is not None else float("inf")
+
+        for cand in self._visit(query, bound):
+            if best is None or cand < best:
+                best = cand
+        return (best[1], best[0]) if best is not None else None
+
+    def within_radius(self, query, r):
+        """Indices of the points at distance at most `r` from `query`
+
+        Args:
+            query (Point): the query point
+            r ([type]): the radius
+
+        Returns:
+            List[int]: the indices, in increasing order
+        """
+        return sorted(i for d, i in self._visit(query, lambda: r) if d <= r)
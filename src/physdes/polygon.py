@@ -1,11 +1,21 @@
 from itertools import filterfalse, tee
-from typing import List
+from typing import Iterator, List, Tuple
 
-from .point import Point
+from .point import Orientation, Point, orientation
 from .vector2 import Vector2
 
 
+class PolygonError(ValueError):
+    """Raised when a vertex list does not form a valid polygon"""
+
+
 class Polygon:
+    """Polygon stored as a list of vertices
+
+    The last vertex is implicitly connected back to the first one, so the
+    closing vertex is not repeated.
+    """
+
     def __init__(self, pointset: List[Point]):
         """[summary]
 
@@ -14,6 +24,65 @@ class Polygon:
         """
         self._origin = pointset[0]
         self._vecs = list(c - pointset[0] for c in pointset[1:])
+        # extent of the vertices relative to the origin; translating the
+        # polygon only moves `_origin`, so this stays valid
+        xs = [0] + [v.x for v in self._vecs]
+        ys = [0] + [v.y for v in self._vecs]
+        self._extent = (min(xs), max(xs), min(ys), max(ys))
+
+    @classmethod
+    def try_new(cls, pointset: List[Point]):
+        """Construct a polygon after validating the vertex list
+
+        Args:
+            pointset (List[Point]): the vertices
+
+        Raises:
+            PolygonError: if there are fewer than 3 vertices
+
+        Returns:
+            Polygon: the new polygon
+
+        Examples:
+            >>> P = Polygon.try_new([Point(0, 0), Point(1, 0)])
+            Traceback (most recent call last):
+              ...
+            physdes.polygon.PolygonError: polygon needs at least 3 vertices, got 2
+        """
+        if len(pointset) < 3:
+            raise PolygonError(
+                "polygon needs at least 3 vertices, got {}".format(len(pointset))
+            )
+        return cls(pointset)
+
+    def vertices(self) -> List[Point]:
+        """The vertices of the polygon
+
+        Returns:
+            List[Point]: the vertices, starting from the origin
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+            >>> for p in P.vertices():
+            ...     print(p)
+            (0, 0)
+            (4, 0)
+            (0, 3)
+        """
+        return [self._origin] + [self._origin + v for v in self._vecs]
+
+    def num_vertices(self) -> int:
+        """Number of vertices
+
+        Returns:
+            int: the number of vertices
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+            >>> P.num_vertices()
+            3
+        """
+        return len(self._vecs) + 1
 
     def __iadd__(self, rhs: Vector2):
         """[summary]
@@ -27,6 +96,93 @@ class Polygon:
         self._origin += rhs
         return self
 
+    def translate(self, v: Vector2):
+        """The polygon moved by a displacement
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(2, 0), Point(0, 1)])
+            >>> print(P.translate(Vector2(1, 1)).vertices()[1])
+            (3, 1)
+        """
+        return Polygon([p.translate(v) for p in self.vertices()])
+
+    def scale(self, factor):
+        """The polygon scaled about the origin
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(2, 0), Point(0, 1)])
+            >>> print(P.scale(3).vertices()[2])
+            (0, 3)
+        """
+        return Polygon([p.scale(factor) for p in self.vertices()])
+
+    def rotate_90_ccw(self):
+        """The polygon rotated by 90 degrees counter-clockwise about the origin
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(2, 0), Point(0, 1)])
+            >>> print(P.rotate_90_ccw().vertices()[1])
+            (0, 2)
+        """
+        return Polygon([p.rotate_90_ccw() for p in self.vertices()])
+
+    def reflect_x(self):
+        """The polygon mirrored across the x-axis
+
+        Mirroring reverses the orientation of the vertex list.
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(2, 0), Point(0, 1)])
+            >>> P.reflect_x().is_counter_clockwise()
+            False
+        """
+        return Polygon([p.reflect_x() for p in self.vertices()])
+
+    def reflect_y(self):
+        """The polygon mirrored across the y-axis
+
+        Mirroring reverses the orientation of the vertex list.
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(2, 0), Point(0, 1)])
+            >>> print(P.reflect_y().vertices()[1])
+            (-2, 0)
+        """
+        return Polygon([p.reflect_y() for p in self.vertices()])
+
+    def edges(self) -> Iterator[Tuple[Point, Point]]:
+        """The edges as pairs of consecutive vertices
+
+        The last edge wraps around from the last vertex to the first one, so
+        there are as many edges as vertices.
+
+        Returns:
+            Iterator[Tuple[Point, Point]]: the (start, end) pairs
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+            >>> for p, q in P.edges():
+            ...     print(p, q)
+            (0, 0) (4, 0)
+            (4, 0) (0, 3)
+            (0, 3) (0, 0)
+        """
+        S = self.vertices()
+        return zip(S, S[1:] + S[:1])
+
+    def perimeter(self) -> float:
+        """Sum of the Euclidean lengths of the edges
+
+        Returns:
+            float: the perimeter
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+            >>> P.perimeter()
+            12.0
+        """
+        return sum(p.euclidean_distance(q) for p, q in self.edges())
+
     def signed_area_x2(self):
         """[summary]
 
@@ -63,11 +219,239 @@ class Polygon:
             res += v1.x * (v2.y - v0.y)
         return res
 
+    def signed_area(self):
+        """Signed area by the shoelace formula
+
+        The area is positive if the vertices are in counter-clockwise order
+        and negative if they are in clockwise order.
+
+        Returns:
+            [type]: the signed area
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+            >>> P.signed_area()
+            6.0
+            >>> Q = Polygon([Point(0, 0), Point(0, 3), Point(4, 0)])
+            >>> Q.signed_area()
+            -6.0
+        """
+        return self.signed_area_x2() / 2
+
+    def area(self):
+        """Area (absolute value of the signed area)
+
+        Returns:
+            [type]: the area
+
+        Examples:
+            >>> Q = Polygon([Point(0, 0), Point(0, 3), Point(4, 0)])
+            >>> Q.area()
+            6.0
+        """
+        return abs(self.signed_area())
+
+    def is_counter_clockwise(self) -> bool:
+        """Whether the vertices are in counter-clockwise order
+
+        Returns:
+            bool: True if the signed area is positive
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+            >>> P.is_counter_clockwise()
+            True
+        """
+        return self.signed_area_x2() > 0
+
+    def into_counter_clockwise(self):
+        """The same polygon with counter-clockwise vertex order
+
+        The polygon is returned unchanged if it is already counter-clockwise.
+        Otherwise the vertex order is reversed, keeping the first vertex.
+
+        Returns:
+            Polygon: the counter-clockwise polygon
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(0, 3), Point(4, 0)])
+            >>> for p in P.into_counter_clockwise().vertices():
+            ...     print(p)
+            (0, 0)
+            (4, 0)
+            (0, 3)
+        """
+        if self.is_counter_clockwise():
+            return self
+        S = self.vertices()
+        return Polygon(S[:1] + S[:0:-1])
+
+    def simplify(self):
+        """The same shape without redundant vertices
+
+        A vertex is dropped if it lies on the straight segment joining its
+        neighbours (the cross product of the two edges is zero and they
+        point the same way), e.g. a midpoint of an edge or a repeated
+        vertex. Corners and spikes that fold back are kept.
+
+        Returns:
+            Polygon: the simplified polygon
+
+        Examples:
+            >>> coords = [(0, 0), (2, 0), (4, 0), (4, 4), (0, 4)]
+            >>> P = Polygon([Point(x, y) for x, y in coords])
+            >>> P.simplify().num_vertices()
+            4
+        """
+        return Polygon(_drop_collinear(self.vertices()))
+
+    def centroid(self) -> Point:
+        """Area-weighted centroid
+
+        Unlike the average of the vertices, this is the center of mass of
+        the enclosed region. The result is generally not integral, so true
+        division is used.
+
+        Returns:
+            Point: the centroid
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)])
+            >>> print(P.centroid())
+            (0.5, 0.5)
+        """
+        cx = cy = 0
+        vecs = self._vecs
+        for v0, v1 in zip(vecs[:-1], vecs[1:]):
+            cross = v0.cross(v1)
+            cx += (v0.x + v1.x) * cross
+            cy += (v0.y + v1.y) * cross
+        area_x6 = 3 * self.signed_area_x2()
+        return Point(self._origin.x + cx / area_x6, self._origin.y + cy / area_x6)
+
+    def bounding_box(self):
+        """Smallest axis-aligned rectangle containing all the vertices
+
+        Returns:
+            Rect: the bounding box
+
+        Examples:
+            >>> P = Polygon([Point(1, 0), Point(4, 2), Point(0, 3)])
+            >>> print(P.bounding_box())
+            ([0, 4], [0, 3])
+        """
+        from .interval import Interval
+        from .recti import Rect
+
+        xmin, xmax, ymin, ymax = self._extent
+        x0, y0 = self._origin.x, self._origin.y
+        return Rect(Interval(x0 + xmin, x0 + xmax), Interval(y0 + ymin, y0 + ymax))
+
+    def contains_point(self, q: Point) -> bool:
+        """Whether a point lies inside the polygon or on its boundary
+
+        Points outside the bounding box are rejected right away. A point on
+        an edge (including a vertex) counts as inside. Otherwise the even-odd
+        rule is applied with a horizontal ray. To avoid counting
+        a vertex twice, each edge is treated as half-open in y: it includes
+        its lower endpoint and excludes its upper endpoint.
+
+        Args:
+            q (Point): the query point
+
+        Returns:
+            bool: True if `q` is inside or on the boundary
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(4, 4), Point(0, 4)])
+            >>> P.contains_point(Point(2, 2))
+            True
+            >>> P.contains_point(Point(4, 2))
+            True
+            >>> P.contains_point(Point(5, 2))
+            False
+        """
+        xmin, xmax, ymin, ymax = self._extent
+        dx, dy = q.x - self._origin.x, q.y - self._origin.y
+        if not (xmin <= dx <= xmax and ymin <= dy <= ymax):
+            return False
+        S = self.vertices()
+        p0 = S[-1]
+        for p1 in S:
+            if (q - p0).cross(p1 - p0) == 0 and (
+                min(p0.x, p1.x) <= q.x <= max(p0.x, p1.x)
+                and min(p0.y, p1.y) <= q.y <= max(p0.y, p1.y)
+            ):
+                return True
+            p0 = p1
+        return point_in_polygon(S, q)
+
+    def contains_points(self, points: List[Point]) -> List[bool]:
+        """Batch version of `contains_point`
+
+        The edges are sorted by their lower y once, and the queries are
+        processed in increasing y, so each query only looks at the edges
+        whose y-span contains it instead of at every edge.
+
+        Args:
+            points (List[Point]): the query points
+
+        Returns:
+            List[bool]: `contains_point` of each query, in the input order
+
+        Examples:
+            >>> P = Polygon([Point(0, 0), Point(4, 0), Point(4, 4), Point(0, 4)])
+            >>> P.contains_points([Point(2, 2), Point(4, 2), Point(5, 2)])
+            [True, True, False]
+        """
+        S = self.vertices()
+        edges = sorted(
+            (
+                (min(p0.y, p1.y), max(p0.y, p1.y), p0, p1)
+                for p0, p1 in zip(S[-1:] + S, S)
+            ),
+            key=lambda e: e[0],
+        )
+        result = [False] * len(points)
+        active: List = []
+        k = 0
+        for i in sorted(range(len(points)), key=lambda i: points[i].y):
+            q = points[i]
+            while k < len(edges) and edges[k][0] <= q.y:
+                active.append(edges[k])
+                k += 1
+            active = [e for e in active if e[1] >= q.y]
+            c = False
+            for _, _, p0, p1 in active:
+                d = (q - p0).cross(p1 - p0)
+                if d == 0 and min(p0.x, p1.x) <= q.x <= max(p0.x, p1.x):
+                    c = True  # on the boundary
+                    break
+                if (p1.y <= q.y < p0.y and d > 0) or (p0.y <= q.y < p1.y and d < 0):
+                    c = not c
+            result[i] = c
+        return result
+
     def is_rectilinear(self):
         """@todo"""
         pass
 
 
+def _drop_collinear(S: List[Point]) -> List[Point]:
+    """Remove the vertices lying between their neighbours, in place"""
+    k = 0
+    while k < len(S) and len(S) > 3:
+        u = S[k] - S[k - 1]
+        v = S[(k + 1) % len(S)] - S[k]
+        if u.cross(v) == 0 and u.dot(v) >= 0:
+            # the neighbours' own tests are unaffected: the edge through
+            # the removed vertex keeps its direction
+            del S[k]
+        else:
+            k += 1
+    return S
+
+
 def partition(pred, iterable):
     "Use a predicate to partition entries into true entries and false entries"
     # partition(is_odd, range(10)) --> 1 9 3 7 5 and 4 0 8 2 6
@@ -95,6 +479,56 @@ def partition(pred, iterable):
 #     return lst1 + lst2
 
 
+def convex_hull(pointset: List[Point]) -> Polygon:
+    """Convex hull by Andrew's monotone chain algorithm
+
+    The hull vertices are returned in counter-clockwise order, starting
+    from the lowest-leftmost point, with collinear points removed.
+    Degenerate inputs give a polygon with fewer than 3 vertices: one vertex
+    if all points coincide, and the two extreme points if all points are
+    collinear.
+
+    Args:
+        pointset (List[Point]): the points (must not be empty)
+
+    Returns:
+        Polygon: the convex hull
+
+    Examples:
+        >>> coords = [(0, 0), (2, 0), (1, 1), (2, 2), (0, 2), (1, 0)]
+        >>> P = convex_hull([Point(x, y) for x, y in coords])
+        >>> for p in P.vertices():
+        ...     print(p)
+        (0, 0)
+        (2, 0)
+        (2, 2)
+        (0, 2)
+    """
+    assert len(pointset) >= 1
+    pts = []
+    for p in sorted(pointset):
+        if not pts or pts[-1] != p:
+            pts.append(p)
+    if len(pts) <= 2:
+        return Polygon(pts)
+
+    def half_hull(seq):
+        chain = []
+        for p in seq:
+            while (
+                len(chain) >= 2
+                and orientation(chain[-2], chain[-1], p)
+                != Orientation.COUNTER_CLOCKWISE
+            ):
+                chain.pop()
+            chain.append(p)
+        return chain
+
+    lower = half_hull(pts)
+    upper = half_hull(reversed(pts))
+    return Polygon(lower[:-1] + upper[:-1])
+
+
 def create_mono_polygon(lst, dir):
     """[summary]
 
@@ -1,16 +1,27 @@
-from .interval import Interval
+import json
+import math
+from bisect import bisect_right
+
+from .interval import Interval, enlarge
 from .point import Point
+from .polygon import Polygon
+from .vector2 import Vector2
 
 
 class Rect(Point):
-    def __init__(self, x: Interval, y: Interval):
+    def __init__(self, x: Interval = None, y: Interval = None):
         """[summary]
 
+        A missing interval defaults to [0, 0], so `Rect()` is the degenerate
+        rectangle at the origin.
+
         Args:
             x (Interval): [description]
             y (Interval): [description]
 
         Examples:
+            >>> print(Rect())
+            ([0, 0], [0, 0])
             >>> a = Rect(Interval(3, 4), Interval(5, 6))
             >>> print(a)
             ([3, 4], [5, 6])
@@ -18,7 +29,9 @@ class Rect(Point):
             >>> print(a3d)
             (([3, 4], [5, 6]), [7, 8])
         """
-        Point.__init__(self, x, y)
+        Point.__init__(
+            self, Interval() if x is None else x, Interval() if y is None else y
+        )
 
     @property
     def lb(self):
@@ -67,6 +80,90 @@ class Rect(Point):
     # def __eq__(self, rhs) -> bool:
     #     return self.x == rhs.x and self.y == rhs.y
 
+    @staticmethod
+    def unit():
+        """The unit square [0, 1] x [0, 1]
+
+        Examples:
+            >>> print(Rect.unit())
+            ([0, 1], [0, 1])
+        """
+        return Rect(Interval.unit(), Interval.unit())
+
+    @staticmethod
+    def bounding_of_points(points):
+        """Bounding box of a point set
+
+        Args:
+            points (Iterable[Point]): the points
+
+        Returns:
+            Optional[Rect]: the smallest rectangle containing all points
+                (degenerate for a single point), or None if there are none
+
+        Examples:
+            >>> pts = [Point(3, 1), Point(-2, 4), Point(0, 0)]
+            >>> print(Rect.bounding_of_points(pts))
+            ([-2, 3], [0, 4])
+        """
+        pts = list(points)
+        if not pts:
+            return None
+        xs = [p.x for p in pts]
+        ys = [p.y for p in pts]
+        return Rect(Interval(min(xs), max(xs)), Interval(min(ys), max(ys)))
+
+    def sort_key(self):
+        """Key of the canonical ordering of rectangles
+
+        Returns:
+            tuple: (lower x, lower y, upper x, upper y)
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 6))
+            >>> a.sort_key()
+            (3, 5, 4, 6)
+        """
+        return (self.x.lb, self.y.lb, self.x.ub, self.y.ub)
+
+    def __lt__(self, rhs) -> bool:
+        """Canonical (lexicographic) ordering by `sort_key`
+
+        This makes sorting a list of rectangles deterministic. Only
+        rectangles are compared this way; for any other object NotImplemented
+        is returned, so that Python falls back to the reflected comparison.
+
+        Args:
+            rhs (Rect): the rectangle to compare with
+
+        Returns:
+            bool: True if `sort_key` of self is smaller than that of `rhs`
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 6))
+            >>> b = Rect(Interval(3, 8), Interval(1, 2))
+            >>> b < a
+            True
+        """
+        if not isinstance(rhs, Rect):
+            return NotImplemented
+        return self.sort_key() < rhs.sort_key()
+
+    def __le__(self, rhs) -> bool:
+        if not isinstance(rhs, Rect):
+            return NotImplemented
+        return self.sort_key() <= rhs.sort_key()
+
+    def __gt__(self, rhs) -> bool:
+        if not isinstance(rhs, Rect):
+            return NotImplemented
+        return self.sort_key() > rhs.sort_key()
+
+    def __ge__(self, rhs) -> bool:
+        if not isinstance(rhs, Rect):
+            return NotImplemented
+        return self.sort_key() >= rhs.sort_key()
+
     def flip(self):
         """[summary]
 
@@ -83,6 +180,48 @@ class Rect(Point):
         """
         return Rect(self.y, self.x)
 
+    def rotate_90_ccw(self):
+        """Rotate by 90 degrees counter-clockwise about the origin
+
+        Negating an interval swaps its bounds, so the result is a valid
+        rectangle again.
+
+        Returns:
+            Rect: the rotated rectangle
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 7))
+            >>> print(a.rotate_90_ccw())
+            ([-7, -5], [3, 4])
+        """
+        return Rect(-self.y, self.x)
+
+    def rotate_90_cw(self):
+        """Rotate by 90 degrees clockwise about the origin
+
+        Returns:
+            Rect: the rotated rectangle
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 7))
+            >>> print(a.rotate_90_cw())
+            ([5, 7], [-4, -3])
+        """
+        return Rect(self.y, -self.x)
+
+    def rotate_180(self):
+        """Rotate by 180 degrees about the origin
+
+        Returns:
+            Rect: the rotated rectangle
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 7))
+            >>> print(a.rotate_180())
+            ([-4, -3], [-7, -5])
+        """
+        return Rect(-self.x, -self.y)
+
     # `a` can be Point, VSegment, HSegment, or Rect
     def contains(self, a) -> bool:
         """[summary]
@@ -101,6 +240,16 @@ class Rect(Point):
             True
             >>> a.contains(Rect(Interval(32, 38), Interval(51, 67)))
             False
+
+            A segment is contained if its fixed coordinate lies in one interval
+            and its span in the other:
+
+            >>> a.contains(VSegment(35, Interval(52, 58)))
+            True
+            >>> a.contains(HSegment(Interval(25, 35), 55))
+            False
+            >>> a.overlaps(HSegment(Interval(25, 35), 55))
+            True
         """
         return self.x.contains(a.x) and self.y.contains(a.y)
 
@@ -123,8 +272,356 @@ class Rect(Point):
         """
         return self.x.len() * self.y.len()
 
+    def perimeter(self):
+        """Length of the boundary of the rectangle
+
+        Returns:
+            [type]: `2 * (width + height)`
+
+        Examples:
+            >>> a = Rect(Interval(30, 40), Interval(50, 65))
+            >>> a.perimeter()
+            50
+        """
+        return 2 * (self.width() + self.height())
+
+    def measure(self):
+        """Area of the rectangle
 
-class VSegment(Point):
+        Returns:
+            [type]: the area
+
+        Examples:
+            >>> a = Rect(Interval(30, 40), Interval(50, 60))
+            >>> a.measure()
+            100
+        """
+        return self.area()
+
+    def to_polygon_oriented(self, clockwise: bool) -> Polygon:
+        """The four corners as a polygon with the requested winding
+
+        Both windings start from the lower-left corner.
+
+        Args:
+            clockwise (bool): True for clockwise, False for counter-clockwise
+
+        Returns:
+            Polygon: the polygon
+
+        Examples:
+            >>> a = Rect(Interval(0, 4), Interval(0, 3))
+            >>> for p in a.to_polygon_oriented(False).vertices():
+            ...     print(p)
+            (0, 0)
+            (4, 0)
+            (4, 3)
+            (0, 3)
+        """
+        ll = Point(self.x.lb, self.y.lb)
+        lr = Point(self.x.ub, self.y.lb)
+        ur = Point(self.x.ub, self.y.ub)
+        ul = Point(self.x.lb, self.y.ub)
+        if clockwise:
+            return Polygon([ll, ul, ur, lr])
+        return Polygon([ll, lr, ur, ul])
+
+    def diagonal_length(self) -> float:
+        """Euclidean distance between the lower-left and upper-right corners
+
+        Returns:
+            float: the diagonal length
+
+        Examples:
+            >>> a = Rect(Interval(0, 3), Interval(0, 4))
+            >>> a.diagonal_length()
+            5.0
+        """
+        return math.hypot(self.width(), self.height())
+
+    def manhattan_radius(self):
+        """Half of the sum of the width and the height
+
+        For integer coordinates the result is rounded down.
+
+        Returns:
+            [type]: the Manhattan radius
+
+        Examples:
+            >>> a = Rect(Interval(0, 3), Interval(0, 4))
+            >>> a.manhattan_radius()
+            3
+            >>> b = Rect(Interval(0.0, 3.0), Interval(0.0, 4.0))
+            >>> b.manhattan_radius()
+            3.5
+        """
+        half_perimeter = self.width() + self.height()
+        if isinstance(half_perimeter, int):
+            return half_perimeter // 2
+        return half_perimeter / 2
+
+    def translate(self, v: Vector2):
+        """Move the rectangle by a displacement
+
+        Args:
+            v (Vector2): displacement
+
+        Returns:
+            Rect: the translated rectangle
+
+        Examples:
+            >>> a = Rect(Interval(30, 40), Interval(50, 60))
+            >>> print(a.translate(Vector2(2, -3)))
+            ([32, 42], [47, 57])
+        """
+        return Rect(self.x + v.x, self.y + v.y)
+
+    def scale(self, factor):
+        """Scale the rectangle about the origin
+
+        Args:
+            factor ([type]): scaling factor (non-negative)
+
+        Returns:
+            Rect: the scaled rectangle
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 7))
+            >>> print(a.scale(2))
+            ([6, 8], [10, 14])
+        """
+        return Rect(self.x * factor, self.y * factor)
+
+    def inflate(self, margin):
+        """Grow the rectangle by a uniform margin on every side
+
+        Args:
+            margin ([type]): the margin
+
+        Returns:
+            Rect: the inflated rectangle
+
+        Examples:
+            >>> a = Rect(Interval(3, 4), Interval(5, 7))
+            >>> print(a.inflate(2))
+            ([1, 6], [3, 9])
+        """
+        return Rect(enlarge(self.x, margin), enlarge(self.y, margin))
+
+    def deflate(self, margin):
+        """Shrink the rectangle by a uniform margin on every side
+
+        Args:
+            margin ([type]): the margin
+
+        Returns:
+            Optional[Rect]: the deflated rectangle, or None if the width or
+                the height would become negative
+
+        Examples:
+            >>> a = Rect(Interval(1, 6), Interval(3, 9))
+            >>> print(a.deflate(2))
+            ([3, 4], [5, 7])
+            >>> print(a.deflate(3))
+            None
+        """
+        xlb, xub = self.x.lb + margin, self.x.ub - margin
+        ylb, yub = self.y.lb + margin, self.y.ub - margin
+        if xub < xlb or yub < ylb:
+            return None
+        return Rect(Interval(xlb, xub), Interval(ylb, yub))
+
+    def overlap_area(self, other):
+        """Area of the intersection with another rectangle
+
+        Args:
+            other (Rect): the other rectangle
+
+        Returns:
+            [type]: the common area, 0 if the rectangles are disjoint or
+                only touch
+
+        Examples:
+            >>> a = Rect(Interval(0, 4), Interval(0, 4))
+            >>> a.overlap_area(Rect(Interval(2, 6), Interval(2, 6)))
+            4
+            >>> a.overlap_area(Rect(Interval(5, 6), Interval(2, 6)))
+            0
+        """
+        if not self.overlaps(other):
+            return 0
+        return self.intersection_with(other).area()
+
+    def difference(self, other):
+        """Parts of the rectangle not covered by another one
+
+        The remainder is split into at most four slabs: the full-width top
+        and bottom slabs, and the left and right slabs between them. The
+        pieces share edges only. Rectangles that merely touch do not cut
+        each other.
+
+        Args:
+            other (Rect): the rectangle to subtract
+
+        Returns:
+            List[Rect]: the top, bottom, left and right pieces that are
+                non-empty; `[self]` if there is no overlap, `[]` if `other`
+                covers `self`
+
+        Examples:
+            >>> a = Rect(Interval(0, 10), Interval(0, 10))
+            >>> for r in a.difference(Rect(Interval(5, 15), Interval(5, 15))):
+            ...     print(r)
+            ([0, 10], [0, 5])
+            ([0, 5], [5, 10])
+        """
+        xlb, xub = max(self.x.lb, other.x.lb), min(self.x.ub, other.x.ub)
+        ylb, yub = max(self.y.lb, other.y.lb), min(self.y.ub, other.y.ub)
+        if not (xlb < xub and ylb < yub):
+            return [self]
+        pieces = []
+        if yub < self.y.ub:
+            pieces.append(Rect(self.x, Interval(yub, self.y.ub)))
+        if self.y.lb < ylb:
+            pieces.append(Rect(self.x, Interval(self.y.lb, ylb)))
+        if self.x.lb < xlb:
+            pieces.append(Rect(Interval(self.x.lb, xlb), Interval(ylb, yub)))
+        if xub < self.x.ub:
+            pieces.append(Rect(Interval(xub, self.x.ub), Interval(ylb, yub)))
+        return pieces
+
+    def iter_grid_points(self):
+        """Lazily iterate over the integer lattice points of the rectangle
+
+        The bounds are inclusive and the points come in row-major order
+        (x varies fastest).
+        `len()` of the iterator gives the number of points still to come.
+
+        Returns:
+            Iterator[Point]: the iterator
+
+        Examples:
+            >>> it = Rect(Interval(0, 1), Interval(5, 6)).iter_grid_points()
+            >>> len(it)
+            4
+            >>> print(next(it), next(it), next(it), len(it))
+            (0, 5) (1, 5) (0, 6) 1
+        """
+        return _GridPointIter(self)
+
+    def intersection_with(self, other):
+        """Intersection with another object
+
+        A segment is clipped to the rectangle: the part of it lying inside
+        is returned as a segment of the same orientation.
+
+        Args:
+            other ([type]): [description]
+
+        Returns:
+            [type]: the clipped segment (or None if it misses the rectangle)
+                if `other` is a VSegment or an HSegment
+
+        Examples:
+            >>> a = Rect(Interval(0, 10), Interval(0, 10))
+            >>> print(a.intersection_with(VSegment(5, Interval(8, 15))))
+            (5, [8, 10])
+            >>> print(a.intersection_with(HSegment(Interval(-5, 5), 12)))
+            None
+        """
+        if isinstance(other, Segment):
+            if not self.overlaps(other):
+                return None
+            return Point.intersection_with(other, self)
+        return Point.intersection_with(self, other)
+
+
+class _GridPointIter:
+    """Iterator over the lattice points of a rectangle, with a known length"""
+
+    __slots__ = ("_xlb", "_ncols", "_ylb", "_next", "_total")
+
+    def __init__(self, rect: Rect):
+        self._xlb, self._ylb = rect.x.lb, rect.y.lb
+        self._ncols = max(rect.x.ub - rect.x.lb + 1, 0)
+        nrows = max(rect.y.ub - rect.y.lb + 1, 0)
+        self._next = 0
+        self._total = self._ncols * nrows
+
+    def __iter__(self):
+        return self
+
+    def __next__(self) -> Point:
+        if self._next >= self._total:
+            raise StopIteration
+        row, col = divmod(self._next, self._ncols)
+        self._next += 1
+        return Point(self._xlb + col, self._ylb + row)
+
+    def __len__(self) -> int:
+        return self._total - self._next
+
+
+class Segment(Point):
+    """Common base of VSegment and HSegment
+
+    A routing result can be stored as a list of `Segment` values mixing
+    both orientations.
+    """
+
+    def is_vertical(self) -> bool:
+        """Whether the segment is vertical
+
+        Returns:
+            bool: True for a VSegment, False for an HSegment
+
+        Examples:
+            >>> VSegment(5, Interval(3, 4)).is_vertical()
+            True
+            >>> HSegment(Interval(3, 4), 5).is_vertical()
+            False
+        """
+        return isinstance(self, VSegment)
+
+    def measure(self):
+        """Length of the segment
+
+        Returns:
+            [type]: the length
+
+        Examples:
+            >>> VSegment(5, Interval(1, 10)).measure()
+            9
+            >>> HSegment(Interval(3, 4), 5).measure()
+            1
+        """
+        return self.length()
+
+    def area(self):
+        """Area of the segment, which is always zero
+
+        Examples:
+            >>> VSegment(5, Interval(1, 10)).area()
+            0
+        """
+        return 0
+
+    def perimeter(self):
+        """Length of the boundary of the segment seen as a flat rectangle
+
+        Both sides are counted, as in `Rect.perimeter` for a zero height.
+
+        Returns:
+            [type]: twice the length
+
+        Examples:
+            >>> HSegment(Interval(3, 7), 5).perimeter()
+            8
+        """
+        return 2 * self.length()
+
+
+class VSegment(Segment):
     def __init__(self, x, y):
         """[summary]
 
@@ -192,8 +689,72 @@ class VSegment(Point):
         """
         return HSegment(self.y, self.x)
 
+    def rotate_90_ccw(self):
+        """Rotate by 90 degrees counter-clockwise about the origin
+
+        Returns:
+            HSegment: the rotated segment, which is horizontal
+
+        Examples:
+            >>> a = VSegment(5, Interval(30, 40))
+            >>> print(a.rotate_90_ccw())
+            ([-40, -30], 5)
+        """
+        return HSegment(-self.y, self.x)
+
+    def rotate_90_cw(self):
+        """Rotate by 90 degrees clockwise about the origin
+
+        Returns:
+            HSegment: the rotated segment, which is horizontal
+
+        Examples:
+            >>> a = VSegment(5, Interval(30, 40))
+            >>> print(a.rotate_90_cw())
+            ([30, 40], -5)
+        """
+        return HSegment(self.y, -self.x)
+
+    def length(self):
+        """Length of the segment (width of the y-interval)
+
+        Returns:
+            [type]: the length
+
+        Examples:
+            >>> a = VSegment(5, Interval(30, 40))
+            >>> a.length()
+            10
+        """
+        return self.y.len()
+
+    def intersection_with(self, other):
+        """Intersection with another object
+
+        A vertical and a horizontal segment either cross at a single point
+        or do not meet at all.
+
+        Args:
+            other ([type]): [description]
 
-class HSegment(Point):
+        Returns:
+            [type]: the crossing Point (or None) if `other` is an HSegment
+
+        Examples:
+            >>> a = VSegment(5, Interval(30, 40))
+            >>> print(a.intersection_with(HSegment(Interval(0, 10), 33)))
+            (5, 33)
+            >>> print(a.intersection_with(HSegment(Interval(0, 4), 33)))
+            None
+        """
+        if isinstance(other, HSegment):
+            if other.x.contains(self.x) and self.y.contains(other.y):
+                return Point(self.x, other.y)
+            return None
+        return Point.intersection_with(self, other)
+
+
+class HSegment(Segment):
     def __init__(self, x, y):
         """[summary]
 
@@ -260,3 +821,347 @@ class HSegment(Point):
             (5, [30, 40])
         """
         return VSegment(self.y, self.x)
+
+    def rotate_90_ccw(self):
+        """Rotate by 90 degrees counter-clockwise about the origin
+
+        Returns:
+            VSegment: the rotated segment, which is vertical
+
+        Examples:
+            >>> a = HSegment(Interval(30, 40), 5)
+            >>> print(a.rotate_90_ccw())
+            (-5, [30, 40])
+        """
+        return VSegment(-self.y, self.x)
+
+    def rotate_90_cw(self):
+        """Rotate by 90 degrees clockwise about the origin
+
+        Returns:
+            VSegment: the rotated segment, which is vertical
+
+        Examples:
+            >>> a = HSegment(Interval(30, 40), 5)
+            >>> print(a.rotate_90_cw())
+            (5, [-40, -30])
+        """
+        return VSegment(self.y, -self.x)
+
+    def length(self):
+        """Length of the segment (width of the x-interval)
+
+        Returns:
+            [type]: the length
+
+        Examples:
+            >>> a = HSegment(Interval(30, 40), 5)
+            >>> a.length()
+            10
+        """
+        return self.x.len()
+
+    def intersection_with(self, other):
+        """Intersection with another object
+
+        Args:
+            other ([type]): [description]
+
+        Returns:
+            [type]: the crossing Point (or None) if `other` is a VSegment
+
+        Examples:
+            >>> a = HSegment(Interval(0, 10), 33)
+            >>> print(a.intersection_with(VSegment(5, Interval(30, 40))))
+            (5, 33)
+        """
+        if isinstance(other, VSegment):
+            return other.intersection_with(self)
+        return Point.intersection_with(self, other)
+
+
+def bounding_box(rects):
+    """Smallest rectangle containing all rectangles
+
+    Args:
+        rects (Iterable[Rect]): the rectangles
+
+    Returns:
+        Optional[Rect]: the bounding box, or None if there are none
+
+    Examples:
+        >>> r1 = Rect(Interval(0, 2), Interval(5, 6))
+        >>> r2 = Rect(Interval(4, 7), Interval(-1, 3))
+        >>> print(bounding_box([r1, r2]))
+        ([0, 7], [-1, 6])
+    """
+    box = None
+    for r in rects:
+        box = r if box is None else box.hull_with(r)
+    return box
+
+
+def par_bounding_box(rects, executor, nchunks=4):
+    """`bounding_box` computed over chunks in parallel
+
+    The rectangles are split into contiguous chunks whose bounding boxes
+    are computed by `executor`, then folded in order, so the result is
+    identical to `bounding_box`. The caller chooses and owns the executor:
+    a `ThreadPoolExecutor` shares the rectangles, while with a
+    `ProcessPoolExecutor` they are pickled to the workers, which only pays
+    off for large inputs.
+
+    Args:
+        rects (Sequence[Rect]): the rectangles
+        executor (Executor): a `concurrent.futures` executor
+        nchunks (int): number of chunks
+
+    Returns:
+        Optional[Rect]: the bounding box, or None if there are none
+
+    Examples:
+        >>> from concurrent.futures import ThreadPoolExecutor
+        >>> r1 = Rect(Interval(0, 2), Interval(5, 6))
+        >>> r2 = Rect(Interval(4, 7), Interval(-1, 3))
+        >>> with ThreadPoolExecutor(max_workers=2) as pool:
+        ...     print(par_bounding_box([r1, r2], pool, nchunks=2))
+        ([0, 7], [-1, 6])
+    """
+    if not rects:
+        return None
+    size = -(-len(rects) // nchunks)
+    chunks = [rects[i : i + size] for i in range(0, len(rects), size)]
+    return bounding_box(list(executor.map(bounding_box, chunks)))
+
+
+def connected_components(rects):
+    """Group rectangles into clusters connected by overlap or abutment
+
+    Union-find is applied over all pairs of rectangles that overlap or
+    touch each other.
+
+    Args:
+        rects (List[Rect]): the rectangles
+
+    Returns:
+        List[List[int]]: the indices of each cluster, in ascending order
+
+    Examples:
+        >>> r1 = Rect(Interval(0, 2), Interval(0, 2))
+        >>> r2 = Rect(Interval(2, 4), Interval(0, 2))
+        >>> r3 = Rect(Interval(5, 6), Interval(0, 2))
+        >>> connected_components([r1, r2, r3])
+        [[0, 1], [2]]
+    """
+    parent = list(range(len(rects)))
+
+    def find(i):
+        while parent[i] != i:
+            parent[i] = parent[parent[i]]
+            i = parent[i]
+        return i
+
+    for i, ri in enumerate(rects):
+        for j in range(i + 1, len(rects)):
+            if ri.overlaps(rects[j]):
+                parent[find(j)] = find(i)
+
+    groups = {}
+    for i in range(len(rects)):
+        groups.setdefault(find(i), []).append(i)
+    return list(groups.values())
+
+
+class _IntervalTree:
+    """Interval tree over a fixed list of intervals, of which some are active
+
+    The intervals are placed in slots by increasing lower bound, and every
+    node of a binary tree over the slots keeps the largest upper bound of
+    the active intervals below it (None if there are none). Activating or
+    deactivating an interval takes O(log n), and a query reporting k
+    intervals visits O((k + 1) log n) nodes.
+    """
+
+    def __init__(self, intervals):
+        order = sorted(range(len(intervals)), key=lambda i: intervals[i].lb)
+        self._intervals = intervals
+        self._lbs = [intervals[i].lb for i in order]
+        self._slot = [0] * len(intervals)
+        for s, i in enumerate(order):
+            self._slot[i] = s
+        self._size = 1
+        while self._size < len(intervals):
+            self._size *= 2
+        self._max = [None] * (2 * self._size)
+        self._key = order  # interval index of each slot
+
+    def _update(self, i, ub):
+        node = self._size + self._slot[i]
+        self._max[node] = ub
+        while node > 1:
+            node //= 2
+            left, right = self._max[2 * node], self._max[2 * node + 1]
+            if left is None or (right is not None and left < right):
+                left = right
+            self._max[node] = left
+
+    def activate(self, i):
+        self._update(i, self._intervals[i].ub)
+
+    def deactivate(self, i):
+        self._update(i, None)
+
+    def overlapping(self, iv):
+        """Indices of the active intervals overlapping (or touching) `iv`"""
+        end = bisect_right(self._lbs, iv.ub)  # slots with lb <= iv.ub
+        res = []
+        stack = [(1, 0, self._size)]
+        while stack:
+            node, lo, hi = stack.pop()
+            m = self._max[node]
+            if lo >= end or m is None or m < iv.lb:
+                continue
+            if hi - lo == 1:
+                res.append(self._key[lo])
+                continue
+            mid = (lo + hi) // 2
+            stack.append((2 * node + 1, mid, hi))
+            stack.append((2 * node, lo, mid))
+        return res
+
+
+def find_overlapping_pairs(rects):
+    """All pairs of overlapping (or touching) rectangles
+
+    The rectangles are swept from left to right. The active set holds the
+    rectangles whose x-range contains the sweep line, in an interval tree
+    over their y-ranges, so each new rectangle only visits the active ones
+    whose y-range may overlap its own.
+
+    Args:
+        rects (List[Rect]): the rectangles
+
+    Returns:
+        List[Tuple[int, int]]: the index pairs (i, j) with i < j, in
+            increasing order
+
+    Examples:
+        >>> r1 = Rect(Interval(0, 2), Interval(0, 2))
+        >>> r2 = Rect(Interval(1, 3), Interval(1, 3))
+        >>> r3 = Rect(Interval(5, 6), Interval(0, 2))
+        >>> r4 = Rect(Interval(3, 5), Interval(2, 4))
+        >>> find_overlapping_pairs([r1, r2, r3, r4])
+        [(0, 1), (1, 3), (2, 3)]
+    """
+    # starts come before ends at the same x, so that touching counts
+    events = sorted(
+        (x, kind, i)
+        for i, r in enumerate(rects)
+        for x, kind in ((r.x.lb, 0), (r.x.ub, 1))
+    )
+    active = _IntervalTree([r.y for r in rects])
+    pairs = []
+    for _, kind, i in events:
+        if kind == 1:
+            active.deactivate(i)
+            continue
+        for j in active.overlapping(rects[i].y):
+            pairs.append((min(i, j), max(i, j)))
+        active.activate(i)
+    return sorted(pairs)
+
+
+def routing_channels(blocks, region):
+    """Free rectangular channels between blocks within a region
+
+    The region is first sliced vertically at every block side. Within each
+    vertical slab, the parts of the slab not covered by a block are free.
+    Free pieces with the same y-range in consecutive slabs are then merged
+    horizontally into a single channel.
+
+    Args:
+        blocks (List[Rect]): the placed blocks
+        region (Rect): the routing region
+
+    Returns:
+        List[Rect]: channels that only share edges with each other and with
+            the blocks, ordered by their left side, then by their bottom
+
+    Examples:
+        >>> region = Rect(Interval(0, 30), Interval(0, 10))
+        >>> b1 = Rect(Interval(0, 10), Interval(0, 10))
+        >>> b2 = Rect(Interval(20, 30), Interval(0, 10))
+        >>> for c in routing_channels([b1, b2], region):
+        ...     print(c)
+        ([10, 20], [0, 10])
+    """
+    xs = {region.x.lb, region.x.ub}
+    for b in blocks:
+        xs.update(x for x in (b.x.lb, b.x.ub) if region.x.contains(x))
+    xs = sorted(xs)
+    res = []
+    opened = {}  # y-range -> left x of the channel growing to the right
+    for x0, x1 in zip(xs[:-1], xs[1:]):
+        covered = sorted(
+            (max(b.y.lb, region.y.lb), min(b.y.ub, region.y.ub))
+            for b in blocks
+            if b.x.lb <= x0 and x1 <= b.x.ub and b.y.overlaps(region.y)
+        )
+        spans = set()
+        y = region.y.lb
+        for ylb, yub in covered:
+            if y < ylb:
+                spans.add((y, ylb))
+            y = max(y, yub)
+        if y < region.y.ub:
+            spans.add((y, region.y.ub))
+        for span in list(opened):
+            if span not in spans:
+                res.append((opened.pop(span), x0, span))
+        for span in sorted(spans):
+            opened.setdefault(span, x0)
+    for span, xlb in opened.items():
+        res.append((xlb, xs[-1], span))
+    res.sort(key=lambda c: (c[0], c[2]))  # left side, then bottom
+    return [
+        Rect(Interval(xlb, xub), Interval(ylb, yub))
+        for xlb, xub, (ylb, yub) in res
+    ]
+
+
+def write_jsonl(rects, w):
+    """Stream rectangles to a text file as JSON lines
+
+    Each rectangle is written as one JSON object on its own line, so memory
+    stays bounded and the output is diff-friendly.
+
+    Args:
+        rects (List[Rect]): the rectangles
+        w ([type]): writable text stream
+
+    Examples:
+        >>> import io
+        >>> buf = io.StringIO()
+        >>> write_jsonl([Rect(Interval(3, 4), Interval(5, 6))], buf)
+        >>> print(buf.getvalue(), end="")
+        {"x": [3, 4], "y": [5, 6]}
+    """
+    for r in rects:
+        obj = {"x": [r.x.lb, r.x.ub], "y": [r.y.lb, r.y.ub]}
+        w.write(json.dumps(obj))
+        w.write("\n")
+
+
+def read_jsonl(lines):
+    """Read rectangles written by `write_jsonl`
+
+    Args:
+        lines ([type]): iterable of JSON lines, e.g. a text stream
+
+    Yields:
+        Rect: the next rectangle
+    """
+    for line in lines:
+        if line.strip():
+            obj = json.loads(line)
+            yield Rect(Interval(*obj["x"]), Interval(*obj["y"]))
@@ -0,0 +1,240 @@
+import heapq
+from collections import deque
+from typing import List, NamedTuple, Optional, Tuple
+
+from .interval import Interval
+from .point import Point
+from .recti import HSegment, Rect, Segment, VSegment
+
+
+class Router:
+    def order_nets(self, nets: List[Tuple[Point, Point]]) -> List[int]:
+        """Routing order with the shortest nets first
+
+        Nets are sorted by ascending half-perimeter of their bounding box
+        (the Manhattan distance between the two pins). Ties keep the
+        original order.
+
+        Args:
+            nets (List[Tuple[Point, Point]]): two-pin nets
+
+        Returns:
+            List[int]: indices of `nets` in routing order
+
+        Examples:
+            >>> nets = [(Point(0, 0), Point(5, 5)), (Point(1, 1), Point(2, 3))]
+            >>> Router().order_nets(nets)
+            [1, 0]
+        """
+        return sorted(
+            range(len(nets)), key=lambda i: nets[i][0].min_dist_with(nets[i][1])
+        )
+
+
+class GridRouter:
+    """Maze router on an integer grid
+
+    Cells are the integer points of a bounding rectangle; blocked cells
+    cannot be entered. Moves go in the four axis directions only.
+    """
+
+    _moves = ((1, 0), (0, 1), (-1, 0), (0, -1))
+
+    def __init__(self, region: Rect, blocked=()):
+        """[summary]
+
+        Args:
+            region (Rect): bounding rectangle with integer bounds
+            blocked ([type]): iterable of blocked Points
+        """
+        self._region = region
+        self._blocked = set((p.x, p.y) for p in blocked)
+
+    def _is_free(self, x: int, y: int) -> bool:
+        return (
+            self._region.x.lb <= x <= self._region.x.ub
+            and self._region.y.lb <= y <= self._region.y.ub
+            and (x, y) not in self._blocked
+        )
+
+    def route(self, src: Point, dst: Point) -> Optional[List[Point]]:
+        """Shortest path by Lee's algorithm
+
+        A breadth-first wave is expanded from the source until it reaches
+        the target, then the path is traced back from the target.
+
+        Args:
+            src (Point): the source cell
+            dst (Point): the target cell
+
+        Returns:
+            Optional[List[Point]]: the cells of the path from `src` to `dst`
+                (a single cell if they coincide), or None if the target
+                cannot be reached
+
+        Examples:
+            >>> region = Rect(Interval(0, 2), Interval(0, 2))
+            >>> R = GridRouter(region, [Point(1, 0), Point(1, 1)])
+            >>> path = R.route(Point(0, 0), Point(2, 0))
+            >>> len(path) - 1
+            6
+        """
+        start, goal = (src.x, src.y), (dst.x, dst.y)
+        if not (self._is_free(*start) and self._is_free(*goal)):
+            return None
+        pred = {start: None}
+        queue = deque([start])
+        while queue and goal not in pred:
+            x, y = queue.popleft()
+            for dx, dy in self._moves:
+                nxt = (x + dx, y + dy)
+                if nxt not in pred and self._is_free(*nxt):
+                    pred[nxt] = (x, y)
+                    queue.append(nxt)
+        if goal not in pred:
+            return None
+        return _trace_back(pred, goal)
+
+    def route_astar(self, src: Point, dst: Point):
+        """Shortest path by A* search with the Manhattan heuristic
+
+        The Manhattan distance to the target never overestimates the
+        remaining path length, so the path is as short as the one found by
+        `route`, while typically far fewer cells are expanded. Ties in the
+        f-score are broken by the heuristic and then by insertion order, so
+        repeated runs give identical paths.
+
+        Args:
+            src (Point): the source cell
+            dst (Point): the target cell
+
+        Returns:
+            Tuple[Optional[List[Point]], RouteStats]: the path (or None if
+                the target cannot be reached) and the search statistics
+
+        Examples:
+            >>> region = Rect(Interval(0, 2), Interval(0, 2))
+            >>> R = GridRouter(region, [Point(1, 0), Point(1, 1)])
+            >>> path, stats = R.route_astar(Point(0, 0), Point(2, 0))
+            >>> len(path) - 1
+            6
+        """
+        start, goal = (src.x, src.y), (dst.x, dst.y)
+        if not (self._is_free(*start) and self._is_free(*goal)):
+            return None, RouteStats(0)
+
+        def h(cell):
+            return abs(cell[0] - goal[0]) + abs(cell[1] - goal[1])
+
+        pred = {start: None}
+        g = {start: 0}
+        closed = set()
+        count = 0
+        heap = [(h(start), h(start), count, start)]
+        while heap:
+            _, _, _, cell = heapq.heappop(heap)
+            if cell in closed:
+                continue
+            closed.add(cell)
+            if cell == goal:
+                return _trace_back(pred, goal), RouteStats(len(closed))
+            x, y = cell
+            for dx, dy in self._moves:
+                nxt = (x + dx, y + dy)
+                if nxt in closed or not self._is_free(*nxt):
+                    continue
+                cost = g[cell] + 1
+                if nxt not in g or cost < g[nxt]:
+                    g[nxt] = cost
+                    pred[nxt] = cell
+                    count += 1
+                    heapq.heappush(heap, (cost + h(nxt), h(nxt), count, nxt))
+        return None, RouteStats(len(closed))
+
+
+class RouteStats(NamedTuple):
+    """Statistics of a grid search"""
+
+    expanded: int  # number of cells taken out of the open set
+
+
+def _trace_back(pred, goal) -> List[Point]:
+    path = []
+    cell = goal
+    while cell is not None:
+        path.append(Point(*cell))
+        cell = pred[cell]
+    path.reverse()
+    return path
+
+
+def _hseg(x0, x1, y) -> List[Segment]:
+    if x0 == x1:
+        return []
+    return [HSegment(Interval(min(x0, x1), max(x0, x1)), y)]
+
+
+def _vseg(x, y0, y1) -> List[Segment]:
+    if y0 == y1:
+        return []
+    return [VSegment(x, Interval(min(y0, y1), max(y0, y1)))]
+
+
+def route_l_shaped(src: Point, dst: Point) -> List[List[Segment]]:
+    """Candidate L-shaped routes between two pins
+
+    The first candidate goes horizontally then vertically, the second one
+    vertically then horizontally. If the pins share a row or a column, the
+    two candidates coincide and only the single straight route is returned.
+    The total length of every route equals the Manhattan distance.
+
+    Args:
+        src (Point): the source pin
+        dst (Point): the destination pin
+
+    Returns:
+        List[List[Segment]]: the candidate routes
+
+    Examples:
+        >>> for route in route_l_shaped(Point(0, 0), Point(3, 4)):
+        ...     print(", ".join(str(s) for s in route))
+        ([0, 3], 0), (3, [0, 4])
+        (0, [0, 4]), ([0, 3], 4)
+        >>> route_l_shaped(Point(0, 0), Point(0, 4))[0][0].length()
+        4
+    """
+    hv = _hseg(src.x, dst.x, src.y) + _vseg(dst.x, src.y, dst.y)
+    if src.x == dst.x or src.y == dst.y:
+        return [hv]
+    vh = _vseg(src.x, src.y, dst.y) + _hseg(src.x, dst.x, dst.y)
+    return [hv, vh]
+
+
+def route_z_shaped(src: Point, dst: Point, mid) -> List[Segment]:
+    """Z-shaped route with a vertical jog at x = `mid`
+
+    The route goes horizontally from `src` to x = `mid`, vertically to the
+    row of `dst`, then horizontally to `dst`. Zero-length pieces are
+    dropped.
+
+    Args:
+        src (Point): the source pin
+        dst (Point): the destination pin
+        mid ([type]): x-coordinate of the jog, between src.x and dst.x
+
+    Returns:
+        List[Segment]: the route
+
+    Examples:
+        >>> for s in route_z_shaped(Point(0, 0), Point(6, 4), 2):
+        ...     print(s)
+        ([0, 2], 0)
+        (2, [0, 4])
+        ([2, 6], 4)
+    """
+    assert min(src.x, dst.x) <= mid <= max(src.x, dst.x)
+    return (
+        _hseg(src.x, mid, src.y)
+        + _vseg(mid, src.y, dst.y)
+        + _hseg(mid, dst.x, dst.y)
+    )
@@ -1,10 +1,25 @@
 from itertools import filterfalse, tee
-from typing import List
+from typing import Iterator, List
 
+from .interval import Interval
 from .point import Point
+from .recti import HSegment, Rect, Segment, VSegment
 from .vector2 import Vector2
 
 
+class RPolygonError(ValueError):
+    """Raised when a vertex list does not form a rectilinear polygon
+
+    Attributes:
+        edge (int): index of the first offending edge, where edge `i` joins
+            vertex `i` to vertex `i + 1` (wrapping around)
+    """
+
+    def __init__(self, edge: int, reason: str):
+        ValueError.__init__(self, "edge {}: {}".format(edge, reason))
+        self.edge = edge
+
+
 class RPolygon:
     def __init__(self, pointset: List[Point]):
         """[summary]
@@ -15,6 +30,115 @@ class RPolygon:
         self._origin = pointset[0]
         self._vecs = list(c - pointset[0] for c in pointset[1:])
 
+    @classmethod
+    def try_new(cls, pointset: List[Point]):
+        """Construct a rectilinear polygon from its explicit corners
+
+        Every edge, including the closing one from the last vertex back to
+        the first, must be axis-aligned, and horizontal and vertical edges
+        must alternate.
+
+        Args:
+            pointset (List[Point]): the corners
+
+        Raises:
+            RPolygonError: naming the first edge that is not axis-aligned,
+                or that has the same orientation as the edge before it
+
+        Returns:
+            RPolygon: the new polygon
+
+        Examples:
+            >>> coords = [(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)]
+            >>> P = RPolygon.try_new([Point(x, y) for x, y in coords])
+            >>> P.num_vertices()
+            6
+            >>> coords = [(0, 0), (2, 0), (1, 2)]
+            >>> P = RPolygon.try_new([Point(x, y) for x, y in coords])
+            Traceback (most recent call last):
+              ...
+            physdes.rpolygon.RPolygonError: edge 1: not axis-aligned
+        """
+        n = len(pointset)
+        is_horizontal = []
+        for i in range(n):
+            d = pointset[(i + 1) % n] - pointset[i]
+            if d.y == 0 and d.x != 0:
+                is_horizontal.append(True)
+            elif d.x == 0 and d.y != 0:
+                is_horizontal.append(False)
+            else:
+                raise RPolygonError(i, "not axis-aligned")
+        for i in range(n):
+            if is_horizontal[i] == is_horizontal[i - 1]:
+                raise RPolygonError(i, "same orientation as the previous edge")
+        return cls(pointset)
+
+    def vertices(self) -> List[Point]:
+        """The vertices of the polygon
+
+        Returns:
+            List[Point]: the vertices, starting from the origin
+
+        Examples:
+            >>> P = RPolygon([Point(0, 0), Point(4, 0), Point(4, 3), Point(0, 3)])
+            >>> for p in P.vertices():
+            ...     print(p)
+            (0, 0)
+            (4, 0)
+            (4, 3)
+            (0, 3)
+        """
+        return [self._origin] + [self._origin + v for v in self._vecs]
+
+    def num_vertices(self) -> int:
+        """Number of vertices
+
+        Returns:
+            int: the number of vertices
+        """
+        return len(self._vecs) + 1
+
+    def edges(self) -> Iterator[Segment]:
+        """The edges as axis-aligned segments
+
+        Each vertex is joined to the next one (and the last one back to the
+        first) by a horizontal move followed by a vertical move; for a
+        polygon given by its explicit corners, one of them is empty and
+        there are as many edges as vertices.
+
+        Returns:
+            Iterator[Segment]: the non-empty HSegment and VSegment pieces
+
+        Examples:
+            >>> P = RPolygon([Point(0, 0), Point(4, 0), Point(4, 3), Point(0, 3)])
+            >>> for e in P.edges():
+            ...     print(e)
+            ([0, 4], 0)
+            (4, [0, 3])
+            ([0, 4], 3)
+            (0, [0, 3])
+        """
+        S = self.vertices()
+        for p0, p1 in zip(S, S[1:] + S[:1]):
+            if p0.x != p1.x:
+                yield HSegment(Interval(min(p0.x, p1.x), max(p0.x, p1.x)), p0.y)
+            if p0.y != p1.y:
+                yield VSegment(p1.x, Interval(min(p0.y, p1.y), max(p0.y, p1.y)))
+
+    def perimeter(self):
+        """Sum of the Manhattan lengths of the edges
+
+        Returns:
+            [type]: the perimeter
+
+        Examples:
+            >>> P = RPolygon([Point(0, 0), Point(4, 0), Point(4, 3), Point(0, 3)])
+            >>> P.perimeter()
+            14
+        """
+        return sum(e.length() for e in self.edges())
+
     def __iadd__(self, rhs: Vector2):
         """[summary]
 
@@ -63,6 +187,96 @@ class RPolygon:
             res += v1.x * (v1.y - v0.y)
         return res
 
+    def area(self):
+        """Area of the polygon (absolute value of the signed area)
+
+        Returns:
+            [type]: the area
+
+        Examples:
+            >>> coords = [(0, 0), (0, 3), (4, 3), (4, 0)]
+            >>> P = RPolygon([Point(x, y) for x, y in coords])
+            >>> P.area()
+            12
+        """
+        return abs(self.signed_area())
+
+    def to_rectangles(self) -> List[Rect]:
+        """Decompose the polygon into non-overlapping rectangles
+
+        The plane is cut into horizontal slabs at every vertex y-coordinate.
+        Within a slab, the vertical edges spanning it are sorted by x and
+        paired up, giving the covered x-ranges. Pieces in consecutive slabs
+        with the same x-range are merged.
+
+        Returns:
+            List[Rect]: rectangles that only share edges and whose areas sum
+                to the polygon area
+
+        Examples:
+            >>> coords = [(0, 0), (3, 0), (3, 1), (1, 1), (1, 4), (0, 4)]
+            >>> P = RPolygon([Point(x, y) for x, y in coords])
+            >>> for r in P.to_rectangles():
+            ...     print(r)
+            ([0, 3], [0, 1])
+            ([0, 1], [1, 4])
+        """
+        S = self.vertices()
+        vedges = []  # (x, ylb, yub) of vertical edges
+        p0 = S[-1]
+        for p1 in S:
+            if p0.y != p1.y:
+                vedges.append((p1.x, min(p0.y, p1.y), max(p0.y, p1.y)))
+            p0 = p1
+        ys = sorted(set(p.y for p in S))
+        res = []
+        opened = {}  # x-range -> lower y of the piece growing upwards
+        for y0, y1 in zip(ys[:-1], ys[1:]):
+            xs = sorted(x for x, ylb, yub in vedges if ylb <= y0 and y1 <= yub)
+            spans = set(zip(xs[0::2], xs[1::2]))
+            for span in list(opened):
+                if span not in spans:
+                    res.append((span, opened.pop(span), y0))
+            for span in sorted(spans):
+                opened.setdefault(span, y0)
+        for span, ylb in opened.items():
+            res.append((span, ylb, ys[-1]))
+        return [
+            Rect(Interval(xlb, xub), Interval(ylb, yub))
+            for (xlb, xub), ylb, yub in res
+        ]
+
+    def contains_point(self, q: Point) -> bool:
+        """Whether a point lies inside the polygon or on its boundary
+
+        Since all edges are axis-aligned, only the vertical edges whose
+        y-interval straddles the point need to be compared against it.
+
+        Args:
+            q (Point): the query point
+
+        Returns:
+            bool: True if `q` is inside or on the boundary
+
+        Examples:
+            >>> coords = [(0, 0), (3, 0), (3, 1), (1, 1), (1, 4), (0, 4)]
+            >>> P = RPolygon([Point(x, y) for x, y in coords])
+            >>> P.contains_point(Point(2, 2))
+            False
+            >>> P.contains_point(Point(1, 2))
+            True
+        """
+        S = self.vertices()
+        p0 = S[-1]
+        for p1 in S:
+            # edges run horizontally from p0, then vertically to p1
+            if q.y == p0.y and min(p0.x, p1.x) <= q.x <= max(p0.x, p1.x):
+                return True
+            if q.x == p1.x and min(p0.y, p1.y) <= q.y <= max(p0.y, p1.y):
+                return True
+            p0 = p1
+        return point_in_rpolygon(S, q)
+
     # def contains(self, p):
     #     """inclusively contains a Point p
 
@@ -81,11 +295,165 @@ class RPolygon:
     #                 c = not c
     #     return c
 
+    def simplify(self):
+        """The same shape with consecutive collinear edges merged
+
+        A vertex is dropped if it lies between its neighbours on a common
+        horizontal or vertical line (or repeats its predecessor). This
+        merges consecutive collinear edges, so that horizontal and vertical
+        edges alternate as `try_new` requires.
+
+        Returns:
+            RPolygon: the simplified polygon
+
+        Examples:
+            >>> coords = [(0, 0), (2, 0), (4, 0), (4, 3), (4, 4), (0, 4)]
+            >>> P = RPolygon([Point(x, y) for x, y in coords])
+            >>> P.simplify().num_vertices()
+            4
+        """
+        return RPolygon(_drop_redundant(self.vertices()))
+
+    def snap_to_rectilinear(self, tol):
+        """Snap nearly axis-aligned edges to exactly horizontal or vertical
+
+        The vertices are taken as explicit corners, i.e. each vertex is
+        joined to the next one (and the last one back to the first) by a
+        straight edge. An edge whose x-offset (y-offset) is within `tol` is
+        made vertical (horizontal) by copying the coordinate of its first
+        endpoint to its second endpoint.
+
+        Args:
+            tol ([type]): the largest offset that can be repaired
+
+        Returns:
+            Optional[RPolygon]: the repaired polygon, or None if some edge is
+                too far from axis-aligned to be repaired
+
+        Examples:
+            >>> coords = [(0, 0), (10, 0), (10, 10), (0, 11)]
+            >>> P = RPolygon([Point(x, y) for x, y in coords])
+            >>> Q = P.snap_to_rectilinear(1)
+            >>> Q.signed_area()
+            100
+        """
+        pts = [self._origin] + [self._origin + v for v in self._vecs]
+        xs = [p.x for p in pts]
+        ys = [p.y for p in pts]
+        n = len(pts)
+        for i in range(n):
+            j = (i + 1) % n
+            dx = abs(xs[j] - xs[i])
+            dy = abs(ys[j] - ys[i])
+            if dx <= dy and dx <= tol:
+                xs[j] = xs[i]
+            elif dy <= tol:
+                ys[j] = ys[i]
+            else:
+                return None
+        for i in range(n):
+            j = (i + 1) % n
+            if xs[j] != xs[i] and ys[j] != ys[i]:
+                return None
+        return RPolygon([Point(x, y) for x, y in zip(xs, ys)])
+
     def to_polygon(self):
         """@todo"""
         pass
 
 
+class RPolygonBuilder:
+    """Build a rectilinear polygon by walking along its boundary
+
+    Examples:
+        >>> P = (
+        ...     RPolygonBuilder.start(Point(0, 0))
+        ...     .go_right(3)
+        ...     .go_up(1)
+        ...     .go_left(2)
+        ...     .go_up(2)
+        ...     .go_left(1)
+        ...     .go_down(3)
+        ...     .build()
+        ... )
+        >>> P.num_vertices(), P.signed_area()
+        (6, 5)
+    """
+
+    __slots__ = ("_points",)
+
+    def __init__(self, origin: Point):
+        self._points = [origin]
+
+    @classmethod
+    def start(cls, origin: Point):
+        """Start a walk at the given point
+
+        Args:
+            origin (Point): the first vertex
+
+        Returns:
+            RPolygonBuilder: the new builder
+        """
+        return cls(origin)
+
+    def _go(self, dx, dy):
+        self._points.append(self._points[-1] + Vector2(dx, dy))
+        return self
+
+    def go_right(self, length):
+        """Append an edge of the given length in the +x direction"""
+        return self._go(length, 0)
+
+    def go_up(self, length):
+        """Append an edge of the given length in the +y direction"""
+        return self._go(0, length)
+
+    def go_left(self, length):
+        """Append an edge of the given length in the -x direction"""
+        return self._go(-length, 0)
+
+    def go_down(self, length):
+        """Append an edge of the given length in the -y direction"""
+        return self._go(0, -length)
+
+    def build(self) -> RPolygon:
+        """Close the walk into a polygon
+
+        Raises:
+            RPolygonError: if the walk does not end at its start point, or
+                the vertices do not form a valid rectilinear polygon (see
+                `RPolygon.try_new`)
+
+        Returns:
+            RPolygon: the polygon whose vertices are the turning points
+        """
+        *pts, last = self._points
+        if last != pts[0]:
+            raise RPolygonError(len(pts) - 1, "path does not return to the start")
+        return RPolygon.try_new(pts)
+
+
+def _between(a, b, c) -> bool:
+    return min(a, c) <= b <= max(a, c)
+
+
+def _drop_redundant(S: List[Point]) -> List[Point]:
+    """Remove the vertices in the middle of a straight run, in place"""
+    k = 0
+    while k < len(S) and len(S) > 4:
+        a, b, c = S[k - 1], S[k], S[(k + 1) % len(S)]
+        if (
+            b == a
+            or (a.y == b.y == c.y and _between(a.x, b.x, c.x))
+            or (a.x == b.x == c.x and _between(a.y, b.y, c.y))
+        ):
+            del S[k]
+        else:
+            k += 1
+    return S
+
+
 def partition(pred, iterable):
     "Use a predicate to partition entries into true entries and false entries"
     # partition(is_odd, range(10)) --> 1 9 3 7 5 and 4 0 8 2 6
@@ -265,3 +633,124 @@ def point_in_rpolygon(S, q):
                 c = not c
         p0 = p1
     return c
+
+
+def union_rectangles(rects: List[Rect]) -> List[RPolygon]:
+    """Outlines of the union of rectangles, one per connected component
+
+    The plane is cut into a grid at every rectangle side. A sweep over the
+    vertical slabs, keeping a coverage count per grid row, finds the covered
+    cells. Cells sharing an edge are grouped into components, and the
+    boundary of each component is traced with the interior on the left.
+    Rectangles meeting only at a corner give separate components. Interior
+    holes cannot be represented by an RPolygon and are filled, i.e. each
+    polygon is the outer outline of its component.
+
+    Args:
+        rects (List[Rect]): the rectangles, with integer coordinates
+
+    Returns:
+        List[RPolygon]: counter-clockwise polygons, each starting from the
+            lowest of its leftmost corners, ordered by that corner
+
+    Examples:
+        >>> r1 = Rect(Interval(0, 4), Interval(0, 2))
+        >>> r2 = Rect(Interval(2, 6), Interval(1, 3))
+        >>> [P] = union_rectangles([r1, r2])
+        >>> for p in P.vertices():
+        ...     print(p)
+        (0, 0)
+        (4, 0)
+        (4, 1)
+        (6, 1)
+        (6, 3)
+        (2, 3)
+        (2, 2)
+        (0, 2)
+    """
+    rects = [r for r in rects if r.x.lb < r.x.ub and r.y.lb < r.y.ub]
+    xs = sorted({b for r in rects for b in (r.x.lb, r.x.ub)})
+    ys = sorted({b for r in rects for b in (r.y.lb, r.y.ub)})
+    xi = {x: i for i, x in enumerate(xs)}
+    yi = {y: j for j, y in enumerate(ys)}
+
+    events = sorted(
+        (xi[x], d, yi[r.y.lb], yi[r.y.ub])
+        for r in rects
+        for x, d in ((r.x.lb, 1), (r.x.ub, -1))
+    )
+    count = [0] * len(ys)
+    covered = set()
+    k = 0
+    for i in range(len(xs) - 1):
+        while k < len(events) and events[k][0] == i:
+            _, d, jlb, jub = events[k]
+            for j in range(jlb, jub):
+                count[j] += d
+            k += 1
+        covered.update((i, j) for j in range(len(ys) - 1) if count[j] > 0)
+
+    result = []
+    seen = set()
+    for cell in sorted(covered):
+        if cell in seen:
+            continue
+        comp = [cell]
+        seen.add(cell)
+        for i, j in comp:
+            for nb in ((i + 1, j), (i - 1, j), (i, j + 1), (i, j - 1)):
+                if nb in covered and nb not in seen:
+                    seen.add(nb)
+                    comp.append(nb)
+        result.append(_trace_outline(comp, xs, ys))
+    result.sort(key=lambda P: (P.vertices()[0].x, P.vertices()[0].y))
+    return result
+
+
+def _trace_outline(comp, xs, ys) -> RPolygon:
+    """Outer boundary of a set of edge-connected grid cells"""
+    cells = set(comp)
+    ilb = min(i for i, _ in comp) - 1
+    iub = max(i for i, _ in comp) + 1
+    jlb = min(j for _, j in comp) - 1
+    jub = max(j for _, j in comp) + 1
+    # Fill the holes: the empty cells not reachable from outside. What
+    # remains is simply connected, so its boundary is a single loop.
+    outside = {(ilb, jlb)}
+    stack = [(ilb, jlb)]
+    while stack:
+        i, j = stack.pop()
+        for nb in ((i + 1, j), (i - 1, j), (i, j + 1), (i, j - 1)):
+            if (
+                ilb <= nb[0] <= iub
+                and jlb <= nb[1] <= jub
+                and nb not in cells
+                and nb not in outside
+            ):
+                outside.add(nb)
+                stack.append(nb)
+
+    succ = {}  # corner -> next corner, with the interior on the left
+    for i in range(ilb + 1, iub):
+        for j in range(jlb + 1, jub):
+            if (i, j) in outside:
+                continue
+            corners = [(i, j), (i + 1, j), (i + 1, j + 1), (i, j + 1)]
+            nbs = [(i, j - 1), (i + 1, j), (i, j + 1), (i - 1, j)]
+            for k in range(4):
+                if nbs[k] in outside:
+                    succ[corners[k]] = corners[(k + 1) % 4]
+
+    start = min(succ)
+    loop = [start]
+    cur = succ[start]
+    while cur != start:
+        loop.append(cur)
+        cur = succ[cur]
+    corners = [
+        p
+        for k, p in enumerate(loop)
+        if (p[0] - loop[k - 1][0]) * (loop[(k + 1) % len(loop)][1] - p[1])
+        != (p[1] - loop[k - 1][1]) * (loop[(k + 1) % len(loop)][0] - p[0])
+    ]
+    return RPolygon([Point(xs[i], ys[j]) for i, j in corners])
@@ -0,0 +1,332 @@
+import math
+from typing import Dict, List, Optional, Sequence, Tuple
+
+from .interval import Interval
+from .point import Point
+from .recti import Rect
+
+
+class GridIndex:
+    """Uniform-grid bucket index over a point set
+
+    Points are hashed into square buckets of side `cell_size`, so that a
+    query only has to look at the buckets surrounding its own.
+
+    Examples:
+        >>> index = GridIndex([Point(0, 0), Point(7, 3), Point(12, 12)], 5)
+        >>> index.bucket_of(Point(7, 3))
+        (1, 0)
+        >>> len(index)
+        3
+    """
+
+    __slots__ = ("_points", "_cell_size", "_buckets")
+
+    def __init__(self, points: Sequence[Point], cell_size):
+        """Index the points with the given bucket size
+
+        Args:
+            points (Sequence[Point]): the points to index (by position)
+            cell_size ([type]): the side of a bucket, must be positive
+        """
+        assert cell_size > 0
+        self._points = list(points)
+        self._cell_size = cell_size
+        self._buckets: Dict[Tuple[int, int], List[int]] = {}
+        for i, p in enumerate(self._points):
+            self._buckets.setdefault(self.bucket_of(p), []).append(i)
+
+    def __len__(self) -> int:
+        return len(self._points)
+
+    @property
+    def points(self) -> List[Point]:
+        return self._points
+
+    @property
+    def cell_size(self):
+        return self._cell_size
+
+    def bucket_of(self, p: Point) -> Tuple[int, int]:
+        """The bucket coordinates that a point falls in
+
+        The keys are integers even for float coordinates or bucket sizes.
+
+        Examples:
+            >>> GridIndex([], 2.5).bucket_of(Point(6, -0.5))
+            (2, -1)
+        """
+        return (
+            math.floor(p.x / self._cell_size),
+            math.floor(p.y / self._cell_size),
+        )
+
+    def bucket(self, key: Tuple[int, int]) -> List[int]:
+        """The indices of the points in a bucket (empty if none)"""
+        return self._buckets.get(key, [])
+
+    def bucket_bounds(self) -> Optional[Tuple[int, int, int, int]]:
+        """The range of occupied buckets as (xmin, ymin, xmax, ymax)"""
+        if not self._buckets:
+            return None
+        xs = [k[0] for k in self._buckets]
+        ys = [k[1] for k in self._buckets]
+        return (min(xs), min(ys), max(xs), max(ys))
+
+
+def _ring(cx: int, cy: int, r: int):
+    """The bucket keys at Chebyshev distance exactly `r` from (cx, cy)"""
+    if r == 0:
+        yield (cx, cy)
+        return
+    for x in range(cx - r, cx + r + 1):
+        yield (x, cy - r)
+        yield (x, cy + r)
+    for y in range(cy - r + 1, cy + r):
+        yield (cx - r, y)
+        yield (cx + r, y)
+
+
+def nearest_point_accelerated(query: Point, index: GridIndex) -> Optional[int]:
+    """Index of the point nearest to `query` in the Manhattan metric
+
+    The buckets are visited in rings of growing Chebyshev distance around the
+    bucket of `query`. Every point in ring `r + 1` is more than
+    `r * cell_size` away, so the search stops as soon as the best distance
+    found is within that bound. Ties are resolved towards the smaller index.
+
+    Args:
+        query (Point): the query point
+        index (GridIndex): the indexed candidates
+
+    Returns:
+        Optional[int]: the index of the nearest point, or None if empty
+
+    Examples:
+        >>> index = GridIndex([Point(0, 0), Point(7, 3), Point(12, 12)], 5)
+        >>> nearest_point_accelerated(Point(9, 9), index)
+        2
+        >>> nearest_point_accelerated(Point(9, 9), GridIndex([], 5)) is None
+        True
+    """
+    bounds = index.bucket_bounds()
+    if bounds is None:
+        return None
+    cx, cy = index.bucket_of(query)
+    xmin, ymin, xmax, ymax = bounds
+    max_ring = max(cx - xmin, xmax - cx, cy - ymin, ymax - cy)
+    best = None  # (distance, index)
+    for r in range(max_ring + 1):
+        for key in _ring(cx, cy, r):
+            for i in index.bucket(key):
+                cand = (query.min_dist_with(index.points[i]), i)
+                if best is None or cand < best:
+                    best = cand
+        if best is not None and best[0] <= r * index.cell_size:
+            break
+    return best[1] if best is not None else None
+
+
+class _QuadNode:
+    __slots__ = ("bounds", "depth", "items", "children")
+
+    def __init__(self, bounds: Rect, depth: int):
+        self.bounds = bounds
+        self.depth = depth
+        self.items: Dict[int, Rect] = {}
+        self.children: Optional[List["_QuadNode"]] = None
+
+    def split(self):
+        xc, yc = self.bounds.x.center(), self.bounds.y.center()
+        xs = (Interval(self.bounds.x.lb, xc), Interval(xc, self.bounds.x.ub))
+        ys = (Interval(self.bounds.y.lb, yc), Interval(yc, self.bounds.y.ub))
+        self.children = [_QuadNode(Rect(x, y), self.depth + 1) for y in ys for x in xs]
+
+
+class QuadTree:
+    """Rectangle quadtree supporting incremental insert, remove and query
+
+    A rectangle is kept in the deepest node whose bounds contain it, so
+    rectangles straddling a split line stay in the parent. Rectangles lying
+    (partly) outside the bounds of the tree are kept in the root.
+
+    Examples:
+        >>> qt = QuadTree(Rect(Interval(0, 100), Interval(0, 100)))
+        >>> qt.insert(0, Rect(Interval(10, 20), Interval(10, 20)))
+        >>> qt.insert(1, Rect(Interval(60, 70), Interval(60, 70)))
+        >>> qt.query(Rect(Interval(15, 65), Interval(0, 30)))
+        [0]
+        >>> qt.remove(0)
+        True
+        >>> qt.query(Rect(Interval(15, 65), Interval(0, 30)))
+        []
+    """
+
+    __slots__ = ("_root", "_capacity", "_max_depth", "_where")
+
+    def __init__(self, bounds: Rect, capacity: int = 8, max_depth: int = 8):
+        """Create an empty tree
+
+        Args:
+            bounds (Rect): the area covered by the tree
+            capacity (int): number of rectangles a leaf holds before splitting
+            max_depth (int): depth beyond which leaves are not split
+        """
+        self._root = _QuadNode(bounds, 0)
+        self._capacity = capacity
+        self._max_depth = max_depth
+        self._where: Dict[int, _QuadNode] = {}
+
+    def __len__(self) -> int:
+        return len(self._where)
+
+    def __contains__(self, idx: int) -> bool:
+        return idx in self._where
+
+    def insert(self, idx: int, r: Rect):
+        """Insert a rectangle under the given index
+
+        An existing rectangle with the same index is replaced.
+
+        Args:
+            idx (int): the index of the rectangle
+            r (Rect): the rectangle
+        """
+        self.remove(idx)
+        node = self._root
+        while node.children is not None:
+            child = next((c for c in node.children if c.bounds.contains(r)), None)
+            if child is None:
+                break
+            node = child
+        self._put(node, idx, r)
+
+    def _put(self, node: _QuadNode, idx: int, r: Rect):
+        node.items[idx] = r
+        self._where[idx] = node
+        if (
+            node.children is None
+            and len(node.items) > self._capacity
+            and node.depth < self._max_depth
+        ):
+            node.split()
+            items, node.items = node.items, {}
+            for i, s in items.items():
+                child = next((c for c in node.children if c.bounds.contains(s)), node)
+                child.items[i] = s
+                self._where[i] = child
+
+    def remove(self, idx: int) -> bool:
+        """Remove the rectangle with the given index
+
+        Args:
+            idx (int): the index of the rectangle
+
+        Returns:
+            bool: whether a rectangle was removed
+        """
+        node = self._where.pop(idx, None)
+        if node is None:
+            return False
+        del node.items[idx]
+        return True
+
+    def query(self, area: Rect) -> List[int]:
+        """Indices of the rectangles overlapping `area`, in increasing order
+
+        Args:
+            area (Rect): the query area
+
+        Returns:
+            List[int]: the indices
+        """
+        result = []
+        stack = [self._root]
+        while stack:
+            node = stack.pop()
+            result += [i for i, r in node.items.items() if r.overlaps(area)]
+            if node.children is not None:
+                stack += [c for c in node.children if c.bounds.overlaps(area)]
+        return sorted(result)
+
+
+def _str_pack(entries, capacity: int):
+    """Group (rect, payload) entries into nodes by sort-tile-recursive"""
+    n = len(entries)
+    nslices = math.ceil(math.sqrt(math.ceil(n / capacity)))
+    per_slice = nslices * capacity
+    entries = sorted(entries, key=lambda e: e[0].x.lb + e[0].x.ub)
+    nodes = []
+    for i in range(0, n, per_slice):
+        tile = sorted(entries[i : i + per_slice], key=lambda e: e[0].y.lb + e[0].y.ub)
+        for j in range(0, len(tile), capacity):
+            group = tile[j : j + capacity]
+            mbr = group[0][0]
+            for r, _ in group[1:]:
+                mbr = mbr.hull_with(r)
+            nodes.append((mbr, group))
+    return nodes
+
+
+class RTree:
+    """Static R-tree over rectangles, bulk-loaded by sort-tile-recursive
+
+    The rectangles are sorted by x into vertical slices, and each slice by y
+    into leaves of `capacity` entries; the leaves are packed the same way
+    level by level up to a single root.
+
+    Examples:
+        >>> rects = [Rect(Interval(i, i + 1), Interval(0, 1)) for i in range(0, 20, 2)]
+        >>> tree = RTree(rects, capacity=4)
+        >>> tree.query_overlapping(Rect(Interval(3, 6), Interval(1, 5)))
+        [1, 2, 3]
+    """
+
+    __slots__ = ("_root", "_len")
+
+    def __init__(self, rects: Sequence[Rect], capacity: int = 8):
+        """Bulk-load the rectangles
+
+        Args:
+            rects (Sequence[Rect]): the rectangles, referred to by position
+            capacity (int): maximum number of entries per node
+        """
+        assert capacity >= 2
+        self._len = len(rects)
+        level = [(r, i) for i, r in enumerate(rects)]
+        leaf = True
+        self._root = None
+        while level:
+            nodes = _str_pack(level, capacity)
+            nodes = [(mbr, group, leaf) for mbr, group in nodes]
+            leaf = False
+            if len(nodes) == 1:
+                self._root = nodes[0]
+                break
+            level = [(node[0], node) for node in nodes]
+
+    def __len__(self) -> int:
+        return self._len
+
+    def query_overlapping(self, query: Rect) -> List[int]:
+        """Indices of the stored rectangles overlapping `query`
+
+        Args:
+            query (Rect): the query rectangle
+
+        Returns:
+            List[int]: the indices, in increasing order
+        """
+        result: List[int] = []
+        if self._root is None:
+            return result
+        stack = [self._root]
+        while stack:
+            mbr, group, leaf = stack.pop()
+            if not mbr.overlaps(query):
+                continue
+            if leaf:
+                result += [i for r, i in group if r.overlaps(query)]
+            else:
+                stack += [node for _, node in group]
+        return sorted(result)
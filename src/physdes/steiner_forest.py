@@ -0,0 +1,460 @@
+import heapq
+from bisect import bisect_left
+from typing import Any, Dict, List, NamedTuple, Optional, Sequence, Tuple
+
+from .interval import Interval, merge_intervals
+from .point import Point
+from .recti import HSegment, Rect, Segment, VSegment
+from .router import route_l_shaped
+
+
+def wirelength(segments: Sequence[Segment]):
+    """Total length of a list of segments
+
+    Args:
+        segments (Sequence[Segment]): the segments
+
+    Returns:
+        [type]: the sum of the segment lengths
+
+    Examples:
+        >>> wirelength([HSegment(Interval(0, 3), 0), VSegment(3, Interval(0, 4))])
+        7
+    """
+    return sum(s.length() for s in segments)
+
+
+def hanan_grid(points: Sequence[Point]) -> List[Point]:
+    """Intersections of the horizontal and vertical lines through the points
+
+    Every rectilinear Steiner minimal tree can be embedded with its Steiner
+    points on this grid. Duplicates are removed and the result is ordered
+    by x, then by y.
+
+    Args:
+        points (Sequence[Point]): the pins
+
+    Returns:
+        List[Point]: the grid points
+
+    Examples:
+        >>> for p in hanan_grid([Point(0, 0), Point(2, 1)]):
+        ...     print(p)
+        (0, 0)
+        (0, 1)
+        (2, 0)
+        (2, 1)
+    """
+    xs = sorted({p.x for p in points})
+    ys = sorted({p.y for p in points})
+    return [Point(x, y) for x in xs for y in ys]
+
+
+def _closest_on(seg: Segment, p: Point) -> Point:
+    """The point of `seg` nearest to `p` in the Manhattan metric"""
+    if seg.is_vertical():
+        return Point(seg.x, min(max(p.y, seg.y.lb), seg.y.ub))
+    return Point(min(max(p.x, seg.x.lb), seg.x.ub), seg.y)
+
+
+def _nearest_on_tree(p: Point, pins: List[Point], segs: List[Segment]):
+    """Distance from `p` to the tree and the point of the tree attaining it"""
+    cands = pins + [_closest_on(s, p) for s in segs]
+    return min(((p.min_dist_with(q), q) for q in cands), key=lambda t: t[0])
+
+
+def _merge_segments(segs: Sequence[Segment]) -> List[Segment]:
+    """Merge overlapping or touching collinear segments
+
+    The result covers the same set of points with pairwise non-overlapping
+    collinear pieces, so its total length is the length of the union.
+    """
+    rows: Dict[Tuple[bool, int], List[Interval]] = {}
+    for s in segs:
+        if s.is_vertical():
+            rows.setdefault((True, s.x), []).append(s.y)
+        else:
+            rows.setdefault((False, s.y), []).append(s.x)
+    result: List[Segment] = []
+    for (vertical, pos), ivs in sorted(rows.items()):
+        for a in merge_intervals(ivs):
+            result.append(VSegment(pos, a) if vertical else HSegment(a, pos))
+    return result
+
+
+def _mst_candidates(points: Sequence[Point]) -> List[Tuple[int, int]]:
+    """Candidate edges containing a Manhattan MST (octant sweep)
+
+    For each point only the nearest neighbour in each of the eight octants
+    around it can be an MST edge. Four sweeps over transformed coordinates
+    (each covering two opposite octants) produce at most 4n candidates.
+    """
+    pts = [(p.x, p.y) for p in points]
+    ids = list(range(len(pts)))
+    cands = []
+    for k in range(4):
+        ids.sort(key=lambda i: pts[i][0] + pts[i][1])
+        keys: List = []  # sorted keys -y of the active points
+        active: List[int] = []
+        for i in ids:
+            xi, yi = pts[i]
+            pos = bisect_left(keys, -yi)
+            end = pos
+            while end < len(keys):
+                xj, yj = pts[active[end]]
+                if yi - yj > xi - xj:
+                    break
+                cands.append((i, active[end]))
+                end += 1
+            del keys[pos:end]
+            del active[pos:end]
+            if pos < len(keys) and keys[pos] == -yi:
+                active[pos] = i
+            else:
+                keys.insert(pos, -yi)
+                active.insert(pos, i)
+        if k % 2 == 1:
+            pts = [(-x, y) for x, y in pts]
+        else:
+            pts = [(y, x) for x, y in pts]
+    return cands
+
+
+def manhattan_mst(points: Sequence[Point]) -> List[Tuple[int, int, Any]]:
+    """Minimum spanning tree of a point set under the Manhattan distance
+
+    Rather than the complete graph, Kruskal's algorithm runs on the O(n)
+    candidate edges of the octant sweep, for O(n log n) time overall.
+
+    Args:
+        points (Sequence[Point]): the points
+
+    Returns:
+        List[Tuple[int, int, Any]]: the tree edges (i, j, weight) with
+            i < j, in increasing order of weight
+
+    Examples:
+        >>> pts = [Point(0, 0), Point(5, 0), Point(0, 5), Point(5, 5)]
+        >>> edges = manhattan_mst(pts)
+        >>> len(edges), sum(w for _, _, w in edges)
+        (3, 15)
+    """
+    edges = sorted(
+        (points[i].min_dist_with(points[j]), min(i, j), max(i, j))
+        for i, j in _mst_candidates(points)
+    )
+    parent = list(range(len(points)))
+
+    def find(i):
+        while parent[i] != i:
+            parent[i] = parent[parent[i]]
+            i = parent[i]
+        return i
+
+    tree = []
+    for w, i, j in edges:
+        ri, rj = find(i), find(j)
+        if ri != rj:
+            parent[ri] = rj
+            tree.append((i, j, w))
+    return tree
+
+
+def rectilinear_steiner_tree(points: Sequence[Point]) -> List[Segment]:
+    """Rectilinear Steiner tree connecting all points (heuristic)
+
+    The tree is grown Prim-style: the pin closest to the current tree is
+    attached by an L-shaped route to the nearest point anywhere on the tree,
+    so that every attachment point is a potential Steiner point. Of the two
+    L-orientations, the one that brings the remaining pins closer to the
+    tree is taken. If this happens to be longer than the L-embedded
+    rectilinear MST, the latter is returned instead, so the wirelength never
+    exceeds that of the MST.
+
+    Args:
+        points (Sequence[Point]): the pins
+
+    Returns:
+        List[Segment]: pairwise non-overlapping axis-aligned segments
+
+    Examples:
+        >>> tree = rectilinear_steiner_tree([Point(0, 0), Point(4, 1), Point(1, 4)])
+        >>> wirelength(tree)
+        8
+    """
+    pins: List[Point] = []
+    for p in points:
+        if p not in pins:
+            pins.append(p)
+    if len(pins) < 2:
+        return []
+
+    tree_pins = pins[:1]
+    remaining = pins[1:]
+    segs: List[Segment] = []
+    while remaining:
+        dists = [_nearest_on_tree(p, tree_pins, segs)[0] for p in remaining]
+        i = min(range(len(remaining)), key=lambda k: dists[k])
+        p = remaining.pop(i)
+        _, q = _nearest_on_tree(p, tree_pins, segs)
+        best = None
+        for cand in route_l_shaped(q, p):
+            trial = segs + cand
+            cost = sum(_nearest_on_tree(r, tree_pins, trial)[0] for r in remaining)
+            if best is None or cost < best[0]:
+                best = (cost, cand)
+        segs += best[1]
+        tree_pins.append(p)
+    steiner = _merge_segments(segs)
+
+    mst = _merge_segments(
+        [
+            s
+            for i, j, _ in manhattan_mst(pins)
+            for s in route_l_shaped(pins[i], pins[j])[0]
+        ]
+    )
+    return steiner if wirelength(steiner) <= wirelength(mst) else mst
+
+
+def _hanan_route(sources, targets, xs, ys, free) -> Optional[List[Point]]:
+    """Shortest path over a Hanan grid from any source to any target
+
+    The grid consists of the points (x, y) with x in `xs` and y in `ys`
+    (both sorted), and a step joins two neighbouring grid points. Dijkstra's
+    algorithm is run from all sources at once, with `free(p, q)` deciding
+    whether the step from `p` to `q` may be taken. Sources and targets are
+    given as (x, y) pairs, since a Point is not hashable.
+
+    Returns:
+        Optional[List[Point]]: the grid points of the path, from a source to
+            the first target reached, or None if no target can be reached
+    """
+    col = {x: i for i, x in enumerate(xs)}
+    row = {y: j for j, y in enumerate(ys)}
+    dist = {}
+    pred: Dict[Tuple[int, int], Any] = {}
+    heap = []
+    for x, y in sources:
+        node = (col[x], row[y])
+        dist[node] = 0
+        pred[node] = None
+        heap.append((0, node))
+    heapq.heapify(heap)
+    goals = {(col[x], row[y]) for x, y in targets}
+    while heap:
+        d, (i, j) = heapq.heappop(heap)
+        if d > dist[(i, j)]:
+            continue
+        if (i, j) in goals:
+            path = []
+            node = (i, j)
+            while node is not None:
+                path.append(Point(xs[node[0]], ys[node[1]]))
+                node = pred[node]
+            return path[::-1]
+        here = Point(xs[i], ys[j])
+        for ni, nj in ((i + 1, j), (i - 1, j), (i, j + 1), (i, j - 1)):
+            if not (0 <= ni < len(xs) and 0 <= nj < len(ys)):
+                continue
+            there = Point(xs[ni], ys[nj])
+            nd = d + here.min_dist_with(there)
+            if ((ni, nj) not in dist or nd < dist[(ni, nj)]) and free(here, there):
+                dist[(ni, nj)] = nd
+                pred[(ni, nj)] = (i, j)
+                heapq.heappush(heap, (nd, (ni, nj)))
+    return None
+
+
+class ForestStats(NamedTuple):
+    """Wirelength of a Steiner forest"""
+
+    per_net: List  # wirelength of each net's tree, None if unrouted
+    total: Any  # wirelength of the routed nets
+    unrouted: List[int]  # indices of the nets that could not be routed
+
+
+def _grid_points_on(seg: Segment, xs, ys):
+    """The (x, y) pairs of the grid points lying on a segment"""
+    if seg.is_vertical():
+        return [(seg.x, y) for y in ys if seg.y.lb <= y <= seg.y.ub]
+    return [(x, seg.y) for x in xs if seg.x.lb <= x <= seg.x.ub]
+
+
+def _route_net(net, k, owner, xs, ys) -> Optional[List[Segment]]:
+    """Tree of net `k` over the grid points not owned by another net
+
+    The tree of `rectilinear_steiner_tree` is taken if it is clear of the
+    other nets; otherwise the tree is grown from the first pin by shortest
+    paths over the free grid points, nearest remaining pin first.
+    """
+    tree = rectilinear_steiner_tree([Point(x, y) for x, y in net])
+    if all(
+        owner.get(p, k) == k for seg in tree for p in _grid_points_on(seg, xs, ys)
+    ):
+        return tree
+
+    def free(p, q):
+        return owner.get((q.x, q.y), k) == k
+
+    taken = set(net[:1])
+    remaining = set(net[1:])
+    segs: List[Segment] = []
+    while remaining:
+        path = _hanan_route(taken, remaining, xs, ys, free)
+        if path is None:
+            return None
+        remaining.discard((path[-1].x, path[-1].y))
+        taken.update((q.x, q.y) for q in path)
+        if len(path) > 1:
+            segs += _path_segments(path)
+    return _merge_segments(segs)
+
+
+def build_forest(nets: Sequence[Sequence[Point]]):
+    """Rectilinear Steiner trees for several nets that never touch
+
+    All nets are routed on one shared Hanan grid: the lines through the
+    pins of every net, plus one line beyond the pins on each side. Each
+    grid point belongs to at most one net. The pins are reserved for their
+    nets up front, then the nets are routed in order. A net takes the tree
+    of `rectilinear_steiner_tree` if it is clear of the grid points of the
+    other nets; otherwise its tree is grown from the first pin by shortest
+    paths over the free grid points. Since every wire runs along grid lines
+    between grid points, trees of different nets share no point at all.
+
+    A net is left unrouted if one of its pins also belongs to another net
+    or if it cannot be connected around the earlier nets; its tree is None.
+
+    Args:
+        nets (Sequence[Sequence[Point]]): the pins of each net
+
+    Returns:
+        Tuple[List[Optional[List[Segment]]], ForestStats]: the tree of each
+            net, in the order of `nets`, and their wirelengths
+
+    Examples:
+        >>> nets = [[Point(0, 2), Point(4, 2)], [Point(2, 0), Point(2, 4)]]
+        >>> trees, stats = build_forest(nets)
+        >>> stats
+        ForestStats(per_net=[4, 10], total=14, unrouted=[])
+    """
+    pins = [list(dict.fromkeys((p.x, p.y) for p in net)) for net in nets]
+    every = [p for net in pins for p in net]
+    if not every:
+        return [[] for _ in nets], ForestStats([0] * len(nets), 0, [])
+    xs = sorted({x for x, _ in every})
+    ys = sorted({y for _, y in every})
+    xs = [xs[0] - 1] + xs + [xs[-1] + 1]
+    ys = [ys[0] - 1] + ys + [ys[-1] + 1]
+
+    owner: Dict[Tuple[Any, Any], int] = {}
+    shared = set()
+    for k, net in enumerate(pins):
+        for p in net:
+            if owner.setdefault(p, k) != k:
+                shared.add(owner[p])
+                shared.add(k)
+
+    trees: List[Optional[List[Segment]]] = []
+    for k, net in enumerate(pins):
+        tree = None if k in shared else _route_net(net, k, owner, xs, ys)
+        trees.append(tree)
+        for seg in tree or []:
+            for p in _grid_points_on(seg, xs, ys):
+                owner[p] = k
+
+    per_net = [None if tree is None else wirelength(tree) for tree in trees]
+    unrouted = [k for k, tree in enumerate(trees) if tree is None]
+    total = sum(w for w in per_net if w is not None)
+    return trees, ForestStats(per_net, total, unrouted)
+
+
+def _path_segments(path: List[Point]) -> List[Segment]:
+    """The straight runs of a path of axis-aligned steps"""
+    corners = [path[0]]
+    for k in range(1, len(path) - 1):
+        a, b, c = path[k - 1], path[k], path[k + 1]
+        if (a.x == b.x) != (b.x == c.x):
+            corners.append(b)
+    corners.append(path[-1])
+    return [
+        route_l_shaped(p, q)[0][0] for p, q in zip(corners, corners[1:]) if p != q
+    ]
+
+
+def steiner_tree_avoiding(
+    points: Sequence[Point], blockages: Sequence[Rect]
+) -> Optional[List[Segment]]:
+    """Rectilinear Steiner tree whose wires stay clear of blockages
+
+    The tree is grown as in `rectilinear_steiner_tree`, each pin being
+    attached to the nearest point of the tree. An L-shaped route is used if
+    one of the two is clear of every blockage (boundaries included);
+    otherwise the connection is a shortest path over the Hanan grid of the
+    pins, the two ends and the lines one unit outside each side of every
+    blockage, so the cost depends on the number of pins and blockages but
+    not on their size. The result is not compared against an MST, so its
+    wirelength may exceed that of the obstacle-free tree.
+
+    Args:
+        points (Sequence[Point]): the pins
+        blockages (Sequence[Rect]): the blocked areas
+
+    Returns:
+        Optional[List[Segment]]: pairwise non-overlapping segments, or None
+            if a pin lies in a blockage or cannot be reached
+
+    Examples:
+        >>> wall = Rect(Interval(2, 3), Interval(-5, 5))
+        >>> tree = steiner_tree_avoiding([Point(0, 0), Point(5, 0)], [wall])
+        >>> wirelength(tree)
+        17
+    """
+    pins: List[Point] = []
+    for p in points:
+        if p not in pins:
+            pins.append(p)
+    if any(b.contains(p) for b in blockages for p in pins):
+        return None
+    if len(pins) < 2:
+        return []
+
+    xs = {p.x for p in pins}
+    ys = {p.y for p in pins}
+    for b in blockages:
+        xs.update((b.x.lb - 1, b.x.ub + 1))
+        ys.update((b.y.lb - 1, b.y.ub + 1))
+
+    def clear(p, q):
+        return not any(route_l_shaped(p, q)[0][0].overlaps(b) for b in blockages)
+
+    tree_pins = pins[:1]
+    remaining = pins[1:]
+    segs: List[Segment] = []
+    while remaining:
+        dists = [_nearest_on_tree(p, tree_pins, segs)[0] for p in remaining]
+        p = remaining.pop(min(range(len(remaining)), key=lambda k: dists[k]))
+        _, q = _nearest_on_tree(p, tree_pins, segs)
+        route = next(
+            (
+                cand
+                for cand in route_l_shaped(q, p)
+                if not any(s.overlaps(b) for s in cand for b in blockages)
+            ),
+            None,
+        )
+        if route is None:
+            path = _hanan_route(
+                [(q.x, q.y)],
+                [(p.x, p.y)],
+                sorted(xs | {q.x}),
+                sorted(ys | {q.y}),
+                clear,
+            )
+            if path is None:
+                return None
+            route = _path_segments(path)
+        segs += route
+        tree_pins.append(p)
+    return _merge_segments(segs)
@@ -1,14 +1,21 @@
+import math
+
+
 class Vector2:
     __slots__ = ("_x", "_y")
 
-    def __init__(self, x, y):
+    def __init__(self, x=0, y=0):
         """[summary]
 
+        The default is the zero vector.
+
         Args:
             x ([type]): [description]
             y ([type]): [description]
 
         Examples:
+            >>> print(Vector2())
+            <0, 0>
             >>> v = Vector2(3, 4)
             >>> print(v)
             <3, 4>
@@ -87,6 +94,37 @@ class Vector2:
         """
         return Vector2(self._x, self._y)
 
+    @classmethod
+    def from_tuple(cls, t):
+        """Construct from an `(x, y)` pair
+
+        Examples:
+            >>> print(Vector2.from_tuple((1, 2)))
+            <1, 2>
+        """
+        x, y = t
+        return cls(x, y)
+
+    def to_tuple(self):
+        """The components as an `(x, y)` pair
+
+        Examples:
+            >>> Vector2(1, 2).to_tuple()
+            (1, 2)
+        """
+        return (self._x, self._y)
+
+    def as_point(self):
+        """The point this vector leads to from the origin
+
+        Examples:
+            >>> print(Vector2(1, 2).as_point())
+            (1, 2)
+        """
+        from .point import Point
+
+        return Point(self._x, self._y)
+
     def cross(self, rhs):
         """[summary]
 
@@ -104,6 +142,193 @@ class Vector2:
         """
         return self._x * rhs._y - rhs._x * self._y
 
+    def translate(self, v):
+        """A displacement is not moved by a translation
+
+        Part of the transform protocol, see `generic.apply_orientation`.
+
+        Returns:
+            Vector2: a copy of the vector
+
+        Examples:
+            >>> print(Vector2(3, 4).translate(Vector2(10, 10)))
+            <3, 4>
+        """
+        return self.copy()
+
+    def reflect_x(self):
+        """Mirror across the x-axis
+
+        Examples:
+            >>> print(Vector2(3, 4).reflect_x())
+            <3, -4>
+        """
+        return Vector2(self._x, -self._y)
+
+    def reflect_y(self):
+        """Mirror across the y-axis
+
+        Examples:
+            >>> print(Vector2(3, 4).reflect_y())
+            <-3, 4>
+        """
+        return Vector2(-self._x, self._y)
+
+    def dot(self, rhs):
+        """Dot product
+
+        Args:
+            rhs (Vector2): the other vector
+
+        Returns:
+            [type]: `x * rhs.x + y * rhs.y`
+
+        Examples:
+            >>> Vector2(3, 4).dot(Vector2(5, 6))
+            39
+        """
+        return self._x * rhs._x + self._y * rhs._y
+
+    def scalar_projection(self, other) -> float:
+        """Signed length of the projection onto `other`
+
+        Args:
+            other (Vector2): the direction to project onto
+
+        Returns:
+            float: `dot(other) / |other|`, or 0.0 if `other` is zero
+
+        Examples:
+            >>> Vector2(3, 3).scalar_projection(Vector2(-2, 0))
+            -3.0
+        """
+        length = other.magnitude()
+        if length == 0:
+            return 0.0
+        return self.dot(other) / length
+
+    def project_onto(self, other):
+        """Vector projection onto `other`
+
+        Args:
+            other (Vector2): the direction to project onto
+
+        Returns:
+            Vector2: `(dot(other) / |other|^2) * other`, or `<0.0, 0.0>` if
+                `other` is zero
+
+        Examples:
+            >>> print(Vector2(3, 3).project_onto(Vector2(1, 0)))
+            <3.0, 0.0>
+        """
+        length_squared = other.dot(other)
+        if length_squared == 0:
+            return Vector2(0.0, 0.0)
+        return other * (self.dot(other) / length_squared)
+
+    def perp(self):
+        """Left-hand perpendicular, i.e. rotated by 90 degrees CCW
+
+        Returns:
+            Vector2: `<-y, x>`
+
+        Examples:
+            >>> print(Vector2(3, 4).perp())
+            <-4, 3>
+        """
+        return Vector2(-self._y, self._x)
+
+    def rotate_90_ccw(self):
+        """Rotate by 90 degrees counter-clockwise (same as `perp`)
+
+        Returns:
+            Vector2: `<-y, x>`
+
+        Examples:
+            >>> print(Vector2(3, 4).rotate_90_ccw())
+            <-4, 3>
+        """
+        return self.perp()
+
+    def rotate_90_cw(self):
+        """Rotate by 90 degrees clockwise
+
+        Returns:
+            Vector2: `<y, -x>`
+
+        Examples:
+            >>> print(Vector2(3, 4).rotate_90_cw())
+            <4, -3>
+        """
+        return Vector2(self._y, -self._x)
+
+    def manhattan_length(self):
+        """Manhattan (L1) length of the vector
+
+        Returns:
+            [type]: `|x| + |y|`
+
+        Examples:
+            >>> Vector2(3, -4).manhattan_length()
+            7
+        """
+        return abs(self._x) + abs(self._y)
+
+    def magnitude(self) -> float:
+        """Euclidean length of the vector
+
+        Returns:
+            float: `sqrt(x * x + y * y)`
+
+        Examples:
+            >>> Vector2(3, 4).magnitude()
+            5.0
+        """
+        return math.hypot(self._x, self._y)
+
+    def normalized(self):
+        """Unit vector with the same direction
+
+        The zero vector has no direction; for it a zero vector is returned,
+        so callers never have to guard against a division by zero.
+
+        Returns:
+            Vector2: the unit vector (or `<0.0, 0.0>`), with float components
+
+        Examples:
+            >>> print(Vector2(3, 4).normalized())
+            <0.6, 0.8>
+            >>> print(Vector2(0, 0).normalized())
+            <0.0, 0.0>
+        """
+        length = self.magnitude()
+        if length == 0:
+            return Vector2(0.0, 0.0)
+        return Vector2(self._x / length, self._y / length)
+
+    def clamp_magnitude(self, max_len: float):
+        """Scale the vector down so that its length does not exceed `max_len`
+
+        Vectors no longer than `max_len` are returned unchanged.
+
+        Args:
+            max_len (float): the largest allowed Euclidean length
+
+        Returns:
+            Vector2: the clamped vector
+
+        Examples:
+            >>> v = Vector2(6, 8)
+            >>> print(v.clamp_magnitude(5.0))
+            <3.0, 4.0>
+            >>> print(v.clamp_magnitude(20.0))
+            <6, 8>
+        """
+        length = self.magnitude()
+        if length <= max_len:
+            return self.copy()
+        return self * (max_len / length)
+
     def __eq__(self, rhs) -> bool:
         """[summary]
 
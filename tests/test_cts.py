@@ -0,0 +1,85 @@
+from random import randint, seed
+
+from physdes.cts import ClockTree, ClockTreeSynthesis
+from physdes.point import Point
+
+
+def _check_embedding(T):
+    """Every wire is at least as long as the distance it spans"""
+    for n in range(1, len(T)):
+        parent = T.parent(n)
+        assert T.position(n).min_dist_with(T.position(parent)) <= T.length(n)
+
+
+def test_cts_two_sinks():
+    s0, s1 = Point(0, 0), Point(4, 2)
+    T = ClockTreeSynthesis.build([s0, s1])
+    root = T.position(0)
+    assert root.min_dist_with(s0) == root.min_dist_with(s1) == 3
+    assert T.wirelength() == 6
+    assert T.skew(0) == T.skew(1) == 0
+    assert T.position(T.sink_node(0)) == s0
+    assert T.position(T.sink_node(1)) == s1
+    _check_embedding(T)
+
+
+def test_cts_four_symmetric_sinks():
+    sinks = [Point(0, 0), Point(10, 0), Point(0, 10), Point(10, 10)]
+    T = ClockTreeSynthesis.build(sinks)
+    assert T.num_sinks() == 4
+    assert T.position(0) == Point(5, 5)
+    assert T.wirelength() == 30
+    assert all(T.skew(i) == 0 for i in range(4))
+    assert all(T.position(T.sink_node(i)) == p for i, p in enumerate(sinks))
+    _check_embedding(T)
+
+
+def test_cts_random_zero_skew():
+    seed(13)
+    for n in (1, 3, 9, 20):
+        sinks = [Point(randint(0, 100), randint(0, 100)) for _ in range(n)]
+        T = ClockTreeSynthesis.build(sinks)
+        assert T.num_sinks() == n
+        assert all(T.skew(i) == 0 for i in range(n))
+        _check_embedding(T)
+    assert len(ClockTreeSynthesis.build([])) == 0
+
+
+def test_clock_tree_skew():
+    T = ClockTree()
+    root = T.add_node(Point(0, 0))
+    a = T.add_node(Point(2, 0), root)
+    T.add_node(Point(2, 3), a, sink=0)
+    T.add_node(Point(4, 0), a, length=4, sink=1)
+    assert T.wirelength() == 9
+    assert T.skew(0) == 0
+    assert T.skew(1) == 1
+
+
+def test_clock_tree_latency():
+    #         root(0, 0)
+    #        /          \
+    #    a(4, 0)      b(0, 3)
+    #    /     \          \
+    # s0(4, 2) s1(9, 0)  s2(0, 5) with a snaked wire of length 6
+    T = ClockTree()
+    root = T.add_node(Point(0, 0))
+    a = T.add_node(Point(4, 0), root)
+    b = T.add_node(Point(0, 3), root)
+    T.add_node(Point(4, 2), a, sink=0)
+    T.add_node(Point(9, 0), a, sink=1)
+    T.add_node(Point(0, 5), b, length=6, sink=2)
+    assert T.latency(0) == 6
+    assert T.latency(1) == 9
+    assert T.latency(2) == 9
+    assert T.max_skew() == 3
+    assert T.skew(0) == 0
+    assert T.skew(2) == 3
+    assert ClockTree().max_skew() == 0
+
+
+def test_cts_latency():
+    sinks = [Point(0, 0), Point(10, 0), Point(0, 10), Point(10, 10)]
+    T = ClockTreeSynthesis.build(sinks)
+    assert all(T.latency(i) == 10 for i in range(4))
+    assert T.max_skew() == 0
@@ -0,0 +1,193 @@
+from physdes.generic import (
+    apply_orientation,
+    area_of,
+    collection_centroid,
+    covered_area,
+    measure_of,
+    perimeter_of,
+    scale_all,
+    spatial_join,
+    total_area,
+    total_area_no_overlap,
+    translate_all,
+)
+from physdes.interval import Interval
+from physdes.point import Point
+from physdes.polygon import Polygon
+from physdes.recti import HSegment, Rect, VSegment
+from physdes.rpolygon import RPolygon
+from physdes.vector2 import Vector2
+
+
+def test_measure_of():
+    assert measure_of(Interval(3, 8)) == 5
+    assert measure_of(Rect(Interval(0, 2), Interval(0, 3))) == 6
+    assert measure_of(4) == 0
+    assert measure_of(VSegment(5, Interval(1, 10))) == 9
+    assert measure_of(HSegment(Interval(2, 10), 5)) == 8
+
+
+def test_area_and_perimeter_of():
+    r = Rect(Interval(1, 4), Interval(2, 7))
+    assert area_of(r) == r.width() * r.height() == measure_of(r) == 15
+    assert perimeter_of(r) == 16
+    coords = [(0, 0), (4, 0), (4, 1), (1, 1), (1, 3), (0, 3)]
+    S = [Point(x, y) for x, y in coords]
+    assert area_of(Polygon(S)) == Polygon(S).signed_area_x2() / 2 == 6
+    assert area_of(RPolygon(S)) == 6
+    assert perimeter_of(Polygon(S)) == perimeter_of(RPolygon(S)) == 14
+    s = VSegment(5, Interval(1, 10))
+    assert area_of(s) == 0
+    assert measure_of(s) == 9
+    assert perimeter_of(s) == 18
+
+
+def test_total_area():
+    r1 = Rect(Interval(0, 4), Interval(0, 4))
+    r2 = Rect(Interval(2, 6), Interval(2, 6))
+    r3 = Rect(Interval(10, 11), Interval(10, 11))
+    rects = [r1, r2, r3]
+    assert total_area(rects) == 33
+    assert total_area_no_overlap(rects) == 29
+    assert total_area_no_overlap(rects) < total_area(rects)
+
+
+def test_total_area_disjoint():
+    r1 = Rect(Interval(0, 1), Interval(0, 1))
+    r2 = Rect(Interval(2, 3), Interval(0, 1))
+    assert total_area([r1, r2]) == total_area_no_overlap([r1, r2]) == 2
+    assert total_area_no_overlap([]) == 0
+
+
+def test_covered_area():
+    unit = Rect(Interval(0, 1), Interval(0, 1))
+    assert covered_area([unit, Rect(Interval(3, 4), Interval(5, 6))]) == 2
+    assert covered_area([unit, Rect(Interval(0, 1), Interval(0, 1))]) == 1
+    r1 = Rect(Interval(0, 4), Interval(0, 4))
+    r2 = Rect(Interval(2, 6), Interval(0, 2))  # half outside r1
+    assert covered_area([r1, r2]) == 16 + 8 - 4
+    assert covered_area([]) == 0
+
+
+def test_spatial_join():
+    rects = [
+        Rect(Interval(0, 10), Interval(0, 10)),
+        Rect(Interval(20, 30), Interval(0, 10)),
+    ]
+    pts = [Point(5, 5), Point(25, 1), Point(15, 5), Point(10, 10)]
+    assert spatial_join(rects, pts) == [(0, 0), (0, 3), (1, 1)]
+    assert spatial_join(pts, rects) == [(0, 0), (1, 1), (3, 0)]
+    assert spatial_join(rects, []) == []
+
+
+def test_translate_all():
+    shapes = [
+        Rect(Interval(0, 2), Interval(0, 3)),
+        Point(5, 5),
+        VSegment(1, Interval(0, 4)),
+        HSegment(Interval(0, 4), 1),
+    ]
+    orig = [s.copy() for s in shapes]
+    v = Vector2(3, -1)
+    translate_all(shapes, v)
+    assert shapes[0] == Rect(Interval(3, 5), Interval(-1, 2))
+    assert shapes[1] == Point(8, 4)
+    assert isinstance(shapes[2], VSegment)
+    assert shapes[2] == VSegment(4, Interval(-1, 3))
+    for s, o in zip(shapes, orig):
+        assert type(s) is type(o)
+        assert s == o.translate(v)
+
+
+def test_scale_all():
+    shapes = [
+        Rect(Interval(0, 2), Interval(1, 3)),
+        Point(5, -5),
+        VSegment(1, Interval(0, 4)),
+        HSegment(Interval(0, 4), 1),
+        Polygon([Point(0, 0), Point(2, 0), Point(0, 1)]),
+    ]
+    scale_all(shapes, 3)
+    assert shapes[0] == Rect(Interval(0, 6), Interval(3, 9))
+    assert shapes[1] == Point(15, -15)
+    assert isinstance(shapes[2], VSegment)
+    assert shapes[2] == VSegment(3, Interval(0, 12))
+    assert isinstance(shapes[3], HSegment)
+    assert shapes[3] == HSegment(Interval(0, 12), 3)
+    assert shapes[4].vertices() == [Point(0, 0), Point(6, 0), Point(0, 3)]
+    translate_all(shapes, Vector2(1, 1))
+    assert shapes[4].vertices()[0] == Point(1, 1)
+    assert shapes[2] == VSegment(4, Interval(1, 13))
+
+
+def test_collection_centroid():
+    shapes = [
+        Rect(Interval(0, 4), Interval(0, 2)),
+        Rect(Interval(10, 12), Interval(6, 10)),
+        Point(2, 0),
+    ]
+    assert collection_centroid(shapes) == Point(5.0, 3.0)
+    assert collection_centroid([VSegment(1, Interval(0, 4))]) == Point(1, 2)
+    assert collection_centroid([]) is None
+
+
+def test_apply_orientation_my():
+    assert apply_orientation(Point(3, 4), "MY") == Point(-3, 4)
+    assert apply_orientation(Vector2(3, 4), "MY") == Vector2(-3, 4)
+    r = Rect(Interval(1, 3), Interval(4, 8))
+    assert apply_orientation(r, "MY") == Rect(Interval(-3, -1), Interval(4, 8))
+    v = apply_orientation(VSegment(5, Interval(1, 2)), "MY")
+    assert isinstance(v, VSegment)
+    assert v == VSegment(-5, Interval(1, 2))
+    h = apply_orientation(HSegment(Interval(1, 2), 5), "MY")
+    assert isinstance(h, HSegment)
+    assert h == HSegment(Interval(-2, -1), 5)
+    P = Polygon([Point(0, 0), Point(2, 0), Point(2, 1)])
+    Q = apply_orientation(P, "MY")
+    assert Q.vertices() == [Point(0, 0), Point(-2, 0), Point(-2, 1)]
+    assert Q.area() == P.area()
+
+
+def test_apply_orientation_rotations():
+    r = Rect(Interval(1, 3), Interval(4, 8))
+    assert apply_orientation(r, "R0") == r
+    assert apply_orientation(r, "R90") == Rect(Interval(-8, -4), Interval(1, 3))
+    assert apply_orientation(r, "R180") == Rect(Interval(-3, -1), Interval(-8, -4))
+    assert apply_orientation(apply_orientation(r, "R90"), "R270") == r
+    assert apply_orientation(r, "MXR90") == Rect(Interval(4, 8), Interval(1, 3))
+    assert apply_orientation(r, "MYR90") == Rect(Interval(-8, -4), Interval(-3, -1))
+
+    h = apply_orientation(VSegment(5, Interval(1, 2)), "R90")
+    assert isinstance(h, HSegment)
+    assert h == HSegment(Interval(-2, -1), 5)
+    v = apply_orientation(HSegment(Interval(1, 2), 5), "R270")
+    assert isinstance(v, VSegment)
+    assert v == VSegment(5, Interval(-2, -1))
+
+    # the same orientation applied to all shapes of a cell
+    cell = [Point(1, 2), r, VSegment(5, Interval(1, 2)), Vector2(1, 0)]
+    moved = [apply_orientation(s, "MX").translate(Vector2(0, 10)) for s in cell]
+    assert moved[0] == Point(1, 8)
+    assert moved[1] == Rect(Interval(1, 3), Interval(2, 6))
+    assert moved[2] == VSegment(5, Interval(8, 9))
+    assert moved[3] == Vector2(1, 0)
+
+
+def test_apply_orientation_uses_the_point_transforms():
+    shapes = [
+        Point(3, 4),
+        Vector2(3, 4),
+        Rect(Interval(1, 3), Interval(4, 8)),
+        VSegment(5, Interval(1, 2)),
+        HSegment(Interval(1, 2), 5),
+    ]
+    for s in shapes:
+        assert apply_orientation(s, "MX") == s.reflect_x()
+        assert apply_orientation(s, "MY") == s.reflect_y()
+        assert apply_orientation(s, "R90") == s.rotate_90_ccw()
+        assert apply_orientation(s, "R270") == s.rotate_90_cw()
+        assert type(apply_orientation(s, "R180")) is type(s)
+        assert type(apply_orientation(s, "MX")) is type(s)
+    v = VSegment(5, Interval(1, 2))
+    assert isinstance(v.rotate_90_cw(), HSegment)
+    assert v.rotate_90_cw().rotate_90_ccw() == v
@@ -1,4 +1,16 @@
+from functools import cmp_to_key
+
+import pytest
+
 from physdes.generic import min_dist
+from physdes.interval import (
+    Intersection,
+    interval_gaps,
+    merge_intervals,
+    sort_intervals_by_lb,
+    union_centroid,
+    weighted_quantile,
+)
 from physdes.recti import Interval
 
 # include <recti/halton_int.hpp>
@@ -70,3 +82,144 @@ def test_interval_of_interval():
     assert a.overlaps(b)
     assert b.overlaps(a)
     assert min_dist(a, b) == 0
+
+
+def test_to_track_range():
+    assert Interval(10, 20).to_track_range(5, 0) == (2, 4)
+    assert Interval(11, 19).to_track_range(5, 0) == (3, 3)
+    assert Interval(-7, 3).to_track_range(5, 0) == (-1, 0)
+    assert Interval(10, 20).to_track_range(5, -2) == (3, 4)
+    first, last = Interval(11, 14).to_track_range(5, 0)
+    assert first > last
+
+
+def test_union_centroid():
+    assert union_centroid([Interval(0, 2), Interval(4, 6)]) == 3.0
+    assert union_centroid([Interval(4, 6), Interval(0, 2), Interval(1, 2)]) == 3.0
+    assert union_centroid([Interval(0, 3), Interval(1, 2), Interval(10, 11)]) == 3.75
+    assert union_centroid([]) is None
+    assert union_centroid([Interval(5, 5)]) is None
+
+
+def test_display_units():
+    assert Interval(1, 5).display_units("nm") == "[1nm, 5nm]"
+    assert str(Interval(1, 5)) == "[1, 5]"
+
+
+def test_weighted_quantile():
+    positions = [7, 2, 10, 4, 1]
+    weights = [1.0, 2.0, 1.0, 4.0, 0.5]
+    assert weighted_quantile(positions, weights, 0.5) == 4
+    assert weighted_quantile(positions, weights, 0.0) == 1
+    assert weighted_quantile(positions, weights, 1.0) == 10
+    assert weighted_quantile(positions, weights, 0.1) == 2
+    assert weighted_quantile(positions, weights, 0.85) == 7
+
+
+def test_clamp_reported():
+    a = Interval(3, 8)
+    assert a.clamp_reported(5) == (5, False)
+    assert a.clamp_reported(3) == (3, False)
+    assert a.clamp_reported(8) == (8, False)
+    assert a.clamp_reported(10) == (8, True)
+    assert a.clamp_reported(-1) == (3, True)
+
+
+def test_classify_intersection():
+    a = Interval(0, 5)
+    assert a.classify_intersection(Interval(5, 10)) == (Intersection.TOUCHING, 5)
+    assert Interval(5, 10).classify_intersection(a) == (Intersection.TOUCHING, 5)
+    kind, r = Interval(0, 6).classify_intersection(Interval(5, 10))
+    assert kind == Intersection.OVERLAPPING
+    assert r == Interval(5, 6)
+    assert a.classify_intersection(Interval(6, 10)) == (Intersection.DISJOINT, None)
+    assert a.intersection_with(Interval(5, 10)) == Interval(5, 5)
+
+
+def test_sort_intervals():
+    lst = [Interval(7, 8), Interval(-2, 20), Interval(3, 4), Interval(3, 3)]
+    sort_intervals_by_lb(lst)
+    assert lst == [Interval(-2, 20), Interval(3, 4), Interval(3, 3), Interval(7, 8)]
+    lst.sort(key=cmp_to_key(Interval.cmp_by_ub))
+    assert lst == [Interval(3, 3), Interval(3, 4), Interval(7, 8), Interval(-2, 20)]
+    assert Interval(3, 4).cmp_by_lb(Interval(3, 9)) == 0
+    assert Interval(3, 4).cmp_by_ub(Interval(3, 9)) == -1
+
+
+def test_center_rounding():
+    odd = Interval(0, 3)  # odd width, the midpoint is 1.5
+    assert odd.center() == 1.5
+    assert odd.center_floor() == 1
+    assert odd.center_ceil() == 2
+    assert odd.center_round() == 2
+    even = Interval(2, 8)  # even width, the midpoint is exact
+    assert even.center_floor() == even.center_ceil() == even.center_round() == 5
+    neg = Interval(-7, -2)  # midpoint -4.5
+    assert neg.center_floor() == -5
+    assert neg.center_ceil() == -4
+    assert neg.center_round() == -4
+    assert isinstance(odd.center_floor(), int)
+
+
+def test_invalid_interval_policy():
+    with pytest.raises(AssertionError):
+        Interval(5, 1)
+    bad = Interval.unchecked(5, 1)
+    assert not bad.is_valid()
+    assert bad.normalized() == Interval(1, 5)
+    assert bad.normalized().is_valid()
+    assert Interval(1, 5).normalized() == Interval(1, 5)
+    assert Interval.unchecked(1, 5) == Interval(1, 5)
+    with pytest.raises(AssertionError):
+        Interval(1, 5).intersection_with(bad)
+    with pytest.raises(AssertionError):
+        bad.intersection_with(Interval(1, 5))
+    with pytest.raises(AssertionError):
+        Interval(1, 5).overlaps(bad)
+    assert Interval(1, 5).intersection_with(bad.normalized()) == Interval(1, 5)
+    with pytest.raises(AssertionError):
+        Interval(0, 2).intersection_with(Interval(3, 4))  # disjoint
+
+
+def test_interval_lexicographic_order_and_hash():
+    tracks = [Interval(4, 6), Interval(1, 3), Interval(1, 2), Interval(4, 6)]
+    ordered = sorted(tracks, key=cmp_to_key(Interval.cmp_lexicographic))
+    assert ordered == [Interval(1, 2), Interval(1, 3), Interval(4, 6), Interval(4, 6)]
+    unique = sorted(set(tracks), key=cmp_to_key(Interval.cmp_lexicographic))
+    assert unique == [Interval(1, 2), Interval(1, 3), Interval(4, 6)]
+    assert Interval(1, 3).cmp_lexicographic(Interval(1, 3)) == 0
+    assert hash(Interval(1, 3)) == hash(Interval(1, 3))
+    assert {Interval(1, 3): "a"}[Interval(1, 3)] == "a"
+
+
+def test_merge_intervals():
+    assert merge_intervals([]) == []
+    disjoint = [Interval(7, 9), Interval(1, 2), Interval(4, 5)]
+    assert merge_intervals(disjoint) == [Interval(1, 2), Interval(4, 5), Interval(7, 9)]
+    assert merge_intervals([Interval(1, 3), Interval(3, 5)]) == [Interval(1, 5)]
+    nested = [Interval(0, 10), Interval(2, 3), Interval(9, 12), Interval(14, 14)]
+    assert merge_intervals(nested) == [Interval(0, 12), Interval(14, 14)]
+    assert disjoint[0] == Interval(7, 9)  # the input is left alone
+
+
+def test_interval_gaps():
+    within = Interval(0, 10)
+    assert interval_gaps([], within) == [within]
+    assert interval_gaps([Interval(-1, 4), Interval(4, 12)], within) == []
+    occupied = [Interval(6, 8), Interval(2, 3), Interval(2, 4)]
+    assert interval_gaps(occupied, within) == [
+        Interval(0, 2),
+        Interval(4, 6),
+        Interval(8, 10),
+    ]
+    clipped = [Interval(-5, 3), Interval(9, 15), Interval(20, 30)]
+    assert interval_gaps(clipped, within) == [Interval(3, 9)]
+    assert interval_gaps([Interval(20, 30)], within) == [within]
+
+
+def test_interval_point_and_unit():
+    a = Interval.point(5)
+    assert a == Interval(5, 5)
+    assert a.len() == 0 and a.contains(5)
+    assert Interval.unit() == Interval(0, 1)
+    assert Interval.unit().len() == 1
@@ -0,0 +1,34 @@
+import io
+from random import randint, seed
+
+import pytest
+
+from physdes.interval import Interval
+from physdes.io import deserialize_layer, serialize_layer
+from physdes.recti import Rect
+
+
+def test_layer_round_trip():
+    seed(17)
+    rects = []
+    for _ in range(1000):
+        x, y = randint(-(2**31), 2**31 - 1000), randint(-(2**31), 2**31 - 1000)
+        rects.append(Rect(Interval(x, x + randint(0, 999)), Interval(y, y + 3)))
+    buf = io.BytesIO()
+    serialize_layer(rects, buf)
+    assert len(buf.getvalue()) == 4 + 16 * 1000
+    buf.seek(0)
+    assert deserialize_layer(buf) == rects
+
+
+def test_layer_empty_and_truncated():
+    buf = io.BytesIO()
+    serialize_layer([], buf)
+    assert buf.getvalue() == b"\x00\x00\x00\x00"
+    buf.seek(0)
+    assert deserialize_layer(buf) == []
+
+    buf = io.BytesIO()
+    serialize_layer([Rect(Interval(1, 2), Interval(3, 4))] * 2, buf)
+    with pytest.raises(ValueError):
+        deserialize_layer(io.BytesIO(buf.getvalue()[:-1]))
@@ -0,0 +1,54 @@
+from physdes.line_segment import LineSegment, SegmentIntersection
+from physdes.point import Point
+
+
+def test_line_segment_crossing():
+    s1 = LineSegment(Point(0, 0), Point(6, 3))
+    s2 = LineSegment(Point(0, 3), Point(6, 0))
+    kind, p = s1.intersect_with(s2)
+    assert kind == SegmentIntersection.CROSSING
+    assert p == Point(3, 1.5)
+    assert s2.intersect_with(s1) == (kind, p)
+
+
+def test_line_segment_t_junction():
+    bar = LineSegment(Point(0, 0), Point(4, 4))
+    stem = LineSegment(Point(2, 2), Point(4, 0))
+    assert bar.intersect_with(stem) == (SegmentIntersection.TOUCHING, Point(2, 2))
+    assert stem.intersect_with(bar) == (SegmentIntersection.TOUCHING, Point(2, 2))
+    corner = LineSegment(Point(4, 4), Point(5, 0))
+    assert bar.intersect_with(corner) == (SegmentIntersection.TOUCHING, Point(4, 4))
+
+
+def test_line_segment_parallel():
+    s1 = LineSegment(Point(0, 0), Point(4, 2))
+    s2 = LineSegment(Point(0, 1), Point(4, 3))
+    assert s1.intersect_with(s2) == (SegmentIntersection.DISJOINT, None)
+    s3 = LineSegment(Point(5, 1), Point(3, 5))  # not parallel, but too short
+    assert s1.intersect_with(s3) == (SegmentIntersection.DISJOINT, None)
+
+
+def test_line_segment_collinear():
+    s1 = LineSegment(Point(0, 0), Point(6, 3))
+    kind, s = s1.intersect_with(LineSegment(Point(8, 4), Point(2, 1)))
+    assert kind == SegmentIntersection.OVERLAPPING
+    assert s == LineSegment(Point(2, 1), Point(6, 3))
+    touching = LineSegment(Point(6, 3), Point(10, 5))
+    assert s1.intersect_with(touching) == (SegmentIntersection.TOUCHING, Point(6, 3))
+    apart = LineSegment(Point(8, 4), Point(10, 5))
+    assert s1.intersect_with(apart) == (SegmentIntersection.DISJOINT, None)
+    vertical = LineSegment(Point(1, 0), Point(1, 5))
+    kind, s = vertical.intersect_with(LineSegment(Point(1, 7), Point(1, 3)))
+    assert kind == SegmentIntersection.OVERLAPPING
+    assert s == LineSegment(Point(1, 3), Point(1, 5))
+
+
+def test_line_segment_degenerate():
+    dot = LineSegment(Point(1, 1), Point(1, 1))
+    s = LineSegment(Point(0, 0), Point(2, 0))
+    assert dot.intersect_with(s) == (SegmentIntersection.DISJOINT, None)
+    assert s.intersect_with(dot) == (SegmentIntersection.DISJOINT, None)
+    on = LineSegment(Point(1, 0), Point(1, 0))
+    assert on.intersect_with(s) == (SegmentIntersection.TOUCHING, Point(1, 0))
+    assert s.intersect_with(on) == (SegmentIntersection.TOUCHING, Point(1, 0))
+    assert dot.intersect_with(dot) == (SegmentIntersection.TOUCHING, Point(1, 1))
@@ -0,0 +1,135 @@
+from physdes.generic import overlap
+from physdes.interval import Interval
+from physdes.line_segment import LineSegment
+from physdes.manhattan_arc import (
+    ManhattanArc,
+    ManhattanBall,
+    merging_region,
+    multi_merging_region,
+)
+from physdes.merge_obj import MergeObj
+from physdes.point import Point
+
+
+def test_ManhattanArc():
+    c = Point(10, 20)
+    arc = ManhattanArc(c, 5)
+    assert arc.center == c
+    assert arc.radius == 5
+    P = arc.to_polygon()
+    assert P.num_vertices() == 4
+    for p in P.vertices():
+        assert arc.contains_point(p)
+    assert arc.contains_point(Point(12, 23))
+    assert not arc.contains_point(c)
+    assert not arc.contains_point(Point(12, 22))
+    assert P.area() == 50
+
+
+def test_ManhattanArc_grow():
+    arc = ManhattanArc(Point(0, 0), 2)
+    big = arc.grow(3)
+    assert big.radius == 5
+    assert big.center == arc.center
+    assert not big.is_empty()
+    assert arc.radius == 2
+    small = arc.grow(-3)
+    assert small.radius == 0
+    assert small.is_empty()
+    assert arc.with_radius(9).radius == 9
+    assert arc.with_radius(-1).is_empty()
+
+
+def test_ManhattanBall_contains():
+    ball = ManhattanBall(Point(10, 20), 5)
+    assert ball.contains_point(Point(10, 20))
+    assert ball.contains_point(Point(12, 23))
+    assert not ball.contains_point(Point(13, 23))
+
+
+def test_ManhattanBall_touching():
+    b1 = ManhattanBall(Point(0, 0), 2)
+    b2 = ManhattanBall(Point(3, 1), 2)
+    assert b1.overlaps(b2)
+    assert overlap(b1, b2)
+    s = b1.intersection_with(b2)
+    assert isinstance(s, LineSegment)  # the balls touch along an edge
+    assert s == LineSegment(Point(1, 1), Point(2, 0))
+    for p in (s.start, s.end, Point(1.5, 0.5)):
+        assert b1.contains_point(p) and b2.contains_point(p)
+
+    b3 = ManhattanBall(Point(4, 0), 2)
+    p = b1.intersection_with(b3)
+    assert isinstance(p, Point)  # the balls touch at a corner
+    assert p == Point(2, 0)
+    assert b3.intersection_with(b1) == p
+
+
+def test_ManhattanBall_overlapping():
+    b1 = ManhattanBall(Point(0, 0), 4)
+    b2 = ManhattanBall(Point(2, 2), 4)
+    P = b1.intersection_with(b2)
+    assert P.num_vertices() == 4
+    assert P.is_counter_clockwise()
+    assert P.area() == 16
+    for p in P.vertices():
+        assert b1.contains_point(p) and b2.contains_point(p)
+
+
+def test_ManhattanBall_disjoint():
+    b1 = ManhattanBall(Point(0, 0), 2)
+    b2 = ManhattanBall(Point(3, 2), 2)
+    assert not b1.overlaps(b2)
+    assert b1.intersection_with(b2) is None
+
+
+def test_merging_region_segment():
+    b1 = ManhattanBall(Point(0, 0), 3)
+    b2 = ManhattanBall(Point(4, 2), 3)
+    trr = merging_region(b1, b2)
+    assert trr == MergeObj(Interval(3, 3), Interval(-1, 3))
+    assert trr.x.len() == 0 and trr.y.len() > 0
+
+
+def test_merging_region_point():
+    b1 = ManhattanBall(Point(0, 0), 3)
+    b2 = ManhattanBall(Point(6, 0), 3)
+    trr = merging_region(b1, b2)
+    assert trr == MergeObj(Interval(3, 3), Interval(3, 3))
+    u, v = trr.x.lb, trr.y.lb
+    p = Point((u + v) // 2, (u - v) // 2)
+    assert p.min_dist_with(b1.center) == 3
+    assert p.min_dist_with(b2.center) == 3
+
+
+def test_merging_region_empty():
+    b1 = ManhattanBall(Point(0, 0), 1)
+    b2 = ManhattanBall(Point(3, 3), 3)
+    assert merging_region(b1, b2) is None
+
+
+def _overshoot(p, balls):
+    return max(p.min_dist_with(b.center) - b.radius for b in balls)
+
+
+def test_multi_merging_region_collinear():
+    balls = [ManhattanBall(Point(x, 0), 5) for x in (0, 4, 10)]
+    region, t = multi_merging_region(balls)
+    assert t == 0
+    assert region == MergeObj(Interval(5, 5), Interval(5, 5))
+    assert _overshoot(Point(5, 0), balls) == 0
+    pair = multi_merging_region(balls[:2])[0]
+    assert pair == merging_region(balls[0], balls[1])
+
+
+def test_multi_merging_region_right_triangle():
+    balls = [ManhattanBall(p, 2) for p in (Point(0, 0), Point(4, 0), Point(0, 4))]
+    point, t = multi_merging_region(balls)
+    assert t == 2
+    u, v = point.x, point.y
+    p = Point((u + v) / 2, (u - v) / 2)
+    assert _overshoot(p, balls) == t
+    best = min(
+        _overshoot(Point(x, y), balls) for x in range(-2, 7) for y in range(-2, 7)
+    )
+    assert best == t
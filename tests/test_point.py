@@ -0,0 +1,245 @@
+from dataclasses import dataclass, field
+from functools import cmp_to_key
+from random import randint, seed
+
+from physdes.interval import Interval
+from physdes.point import (
+    KdTree,
+    Orientation,
+    Point,
+    grid_points,
+    hpwl,
+    k_nearest_to,
+    min_enclosing_square,
+    nearest_point_to,
+    nearest_point_with_dist,
+    orientation,
+    translate_points,
+)
+from physdes.recti import Rect
+from physdes.vector2 import Vector2
+
+
+def test_grid_points():
+    region = Rect(Interval(10, 20), Interval(0, 10))
+    pts = list(grid_points(region, 5))
+    assert len(pts) == 9
+    assert pts[0] == Point(10, 0)
+    assert pts[1] == Point(15, 0)
+    assert pts[3] == Point(10, 5)
+    assert pts[-1] == Point(20, 10)
+    assert all(region.contains(p) for p in pts)
+
+
+def test_min_enclosing_square():
+    pts = [Point(10, 20), Point(13, 25), Point(11, 22)]
+    sq = min_enclosing_square(pts)
+    assert sq == Rect(Interval(9, 14), Interval(20, 25))
+    assert sq.width() == sq.height() == 5
+    assert all(sq.contains(p) for p in pts)
+    assert min_enclosing_square([]) is None
+    assert min_enclosing_square([Point(1, 2)]) == Rect(Interval(1, 1), Interval(2, 2))
+
+
+def test_display_units():
+    assert Point(1, 2).display_units("µm") == "(1µm, 2µm)"
+    r = Rect(Interval(1, 5), Interval(2, 3))
+    assert r.display_units("nm") == "([1nm, 5nm], [2nm, 3nm])"
+    assert str(r) == "([1, 5], [2, 3])"
+
+
+def test_translate_points():
+    pts = [Point(3, -4), Point(0, 0), Point(-7, 12), Point(5, 5)]
+    xs = [p.x for p in pts]
+    ys = [p.y for p in pts]
+    translate_points(xs, ys, 6, -2)
+    moved = [p.translate(Vector2(6, -2)) for p in pts]
+    assert [Point(x, y) for x, y in zip(xs, ys)] == moved
+    translate_points([], [], 1, 1)
+
+
+def test_point_rotations():
+    a = Point(3, -4)
+    assert a.rotate_90_ccw() == Point(4, 3)
+    assert a.rotate_90_cw() == Point(-4, -3)
+    assert a.rotate_180() == Point(-3, 4)
+    assert a.rotate_90_ccw().rotate_90_ccw().rotate_90_ccw().rotate_90_ccw() == a
+    assert a.rotate_90_ccw().rotate_90_cw() == a
+    assert a.rotate_90_ccw().rotate_90_ccw() == a.rotate_180()
+
+
+def test_point_reflections():
+    a = Point(3, 4)
+    assert a.reflect_across_vertical(5) == Point(7, 4)
+    assert a.reflect_across_horizontal(-1) == Point(3, -6)
+    assert a.reflect_x() == Point(3, -4)
+    assert a.reflect_y() == Point(-3, 4)
+    assert a.reflect_x().reflect_x() == a
+    assert a.reflect_y().reflect_y() == a
+    assert a.reflect_across_vertical(5).reflect_across_vertical(5) == a
+    assert a.reflect_across_horizontal(7).reflect_across_horizontal(7) == a
+    assert a.reflect_x().reflect_y() == a.rotate_180()
+
+
+def test_euclidean_distance():
+    a, b = Point(1, 2), Point(4, 6)
+    assert a.euclidean_distance(b) == 5.0
+    assert a.euclidean_distance_squared(b) == 25
+    assert isinstance(a.euclidean_distance_squared(b), int)
+    assert a.min_dist_with(b) == 7  # the default metric is Manhattan
+    assert b.euclidean_distance(a) == a.euclidean_distance(b)
+
+
+def test_k_nearest_to():
+    q = Point(0, 0)
+    cands = [Point(3, 0), Point(0, -1), Point(2, 1), Point(-4, 4), Point(1, 2)]
+    assert k_nearest_to(q, cands, 0) == []
+    assert k_nearest_to(q, cands, 2) == [Point(0, -1), Point(3, 0)]
+    # (3, 0), (2, 1) and (1, 2) are tied, they keep their input order
+    assert k_nearest_to(q, cands, 10) == [
+        Point(0, -1),
+        Point(3, 0),
+        Point(2, 1),
+        Point(1, 2),
+        Point(-4, 4),
+    ]
+    assert k_nearest_to(q, [], 3) == []
+
+
+def test_nearest_point_with_dist():
+    q = Point(2, 2)
+    cands = [Point(9, 9), Point(4, 3), Point(0, 1), Point(3, 0), Point(2, 2.5)]
+    i, p, d = nearest_point_with_dist(q, cands)
+    assert (i, p, d) == (4, Point(2, 2.5), 0.5)
+    assert d == q.min_dist_with(p)
+
+    cands = cands[:4]  # (4, 3), (0, 1) and (3, 0) are all at distance 3
+    i, p, d = nearest_point_with_dist(q, cands)
+    assert i == 1
+    assert p is cands[1]
+    assert d == q.min_dist_with(p) == 3
+    assert nearest_point_to(q, cands) is cands[1]
+    assert nearest_point_with_dist(q, []) is None
+    assert nearest_point_to(q, []) is None
+
+
+def test_point_conversions():
+    assert Point.from_tuple((1, 2)) == Point(1, 2)
+    assert Point(1, 2).to_tuple() == (1, 2)
+    assert Point.from_tuple(Point(5, -3).to_tuple()) == Point(5, -3)
+    v = Point(1, 2).as_vector() + Vector2(10, 20)
+    assert isinstance(v, Vector2)
+    assert v == Vector2(11, 22)
+    assert v.as_point() == Point(11, 22)
+    assert Point(1, 2) + Point(3, 4).as_vector() == Point(4, 6)
+
+
+def test_point_comparators():
+    pts = [Point(3, 1), Point(-1, 5), Point(3, -2), Point(0, 0), Point(-1, 2)]
+    pts.sort(key=cmp_to_key(Point.cmp_lexicographic))
+    assert pts == [Point(-1, 2), Point(-1, 5), Point(0, 0), Point(3, -2), Point(3, 1)]
+    pts = [Point(3, 1), Point(-1, 5), Point(3, -2), Point(-1, 2)]
+    pts.sort(key=cmp_to_key(Point.cmp_by_x))  # stable within equal x
+    assert pts == [Point(-1, 5), Point(-1, 2), Point(3, 1), Point(3, -2)]
+
+
+def test_kdtree_nearest():
+    seed(23)
+    for n in (1, 2, 7, 100, 500):
+        pts = [Point(randint(0, 200), randint(0, 200)) for _ in range(n)]
+        T = KdTree.build(pts)
+        assert len(T) == n
+        for _ in range(50):
+            q = Point(randint(-20, 220), randint(-20, 220))
+            i, _, d = nearest_point_with_dist(q, pts)
+            assert T.nearest(q) == (i, d)  # ties go to the smaller index
+            assert pts[T.nearest(q)[0]] is nearest_point_to(q, pts)
+    assert KdTree.build([]).nearest(Point(0, 0)) is None
+
+
+def test_kdtree_within_radius():
+    seed(29)
+    pts = [Point(randint(0, 100), randint(0, 100)) for _ in range(300)]
+    T = KdTree.build(pts)
+    for _ in range(30):
+        q = Point(randint(0, 100), randint(0, 100))
+        r = randint(0, 30)
+        expected = [i for i, p in enumerate(pts) if q.min_dist_with(p) <= r]
+        assert T.within_radius(q, r) == expected
+    assert KdTree.build([]).within_radius(Point(0, 0), 5) == []
+
+
+def test_manhattan_distance():
+    a, b = Point(1, 2), Point(4, -2)
+    assert a.manhattan_distance(b) == a.min_dist_with(b) == 7
+    assert b.manhattan_distance(a) == 7
+    assert a.manhattan_distance(a) == 0
+    assert (b - a).manhattan_length() == a.manhattan_distance(b)
+
+
+def test_hpwl():
+    a, b = Point(-2, 7), Point(4, 3)
+    assert hpwl([a, b]) == a.min_dist_with(b) == 10
+    pins = [Point(3, 1), Point(-2, 4), Point(0, 0), Point(5, -3), Point(1, 1)]
+    assert hpwl(pins) == 7 + 7
+    assert hpwl([Point(1, 1)]) == 0
+    assert hpwl([]) == 0
+
+
+def test_enlarge_to_rect():
+    pad = Point(0, 0).enlarge_to_rect(2)
+    assert isinstance(pad, Rect)
+    assert pad == Rect(Interval(-2, 2), Interval(-2, 2))
+    assert pad.area() == 16
+    box = Point(3, 1).hull_rect_with(Point(0, 4))
+    assert isinstance(box, Rect)
+    assert box == Rect(Interval(0, 3), Interval(1, 4))
+    assert Point(3, 1).hull_with(Point(0, 4)) == box  # same extent
+
+
+def test_orientation():
+    a, b = Point(1, 1), Point(4, 2)
+    assert orientation(a, b, Point(2, 5)) == Orientation.COUNTER_CLOCKWISE
+    assert orientation(a, b, Point(5, -1)) == Orientation.CLOCKWISE
+    assert orientation(a, b, Point(7, 3)) == Orientation.COLLINEAR
+    assert orientation(a, b, Point(-2, 0)) == Orientation.COLLINEAR
+    assert orientation(b, a, Point(2, 5)) == Orientation.CLOCKWISE
+    assert orientation(a, a, b) == Orientation.COLLINEAR
+
+
+def test_origin():
+    assert Point.origin() == Point(0, 0)
+    assert Point(3, -4) - Point.origin() == Vector2(3, -4)
+
+
+@dataclass
+class _Pin:
+    position: Point = field(default_factory=Point)
+    offset: Vector2 = field(default_factory=Vector2)
+    track: Interval = field(default_factory=Interval)
+    pad: Rect = field(default_factory=Rect)
+
+
+def test_defaults():
+    assert Point() == Point.origin()
+    assert Vector2() == Vector2(0, 0)
+    assert Interval() == Interval.point(0)
+    assert Rect() == Rect(Interval(0, 0), Interval(0, 0))
+    a, b = _Pin(), _Pin()
+    assert a.position == Point(0, 0) and a.pad.area() == 0
+    a.track += 3
+    assert b.track == Interval(0, 0)  # not shared between instances
+    pins = [_Pin() for _ in range(3)]
+    pins += [_Pin(position=Point(1, 2))] * 2
+    assert len(pins) == 5 and pins[-1].offset == Vector2()
+
+
+def test_displacement_and_midpoint():
+    a, b = Point(-3, 5), Point(4, 1)
+    assert a.displacement_to(b) == b - a == Vector2(7, -4)
+    assert a.displacement_from(b) == a - b == Vector2(-7, 4)
+    assert a.translate(a.displacement_to(b)) == b
+    assert Point(0, 0).midpoint(Point(4, 6)) == Point(2, 3)
+    assert a.midpoint(b) == Point(0.5, 3)
+    assert a.midpoint(b) == b.midpoint(a)
+    assert -a == a.rotate_180() == Point(3, -5)
@@ -1,12 +1,19 @@
+import pytest
+
 from physdes.halton_int import halton
+from physdes.interval import Interval
 from physdes.point import Point
 from physdes.polygon import (
     Polygon,
+    PolygonError,
+    convex_hull,
     create_test_polygon,
     create_xmono_polygon,
     create_ymono_polygon,
     point_in_polygon,
 )
+from physdes.recti import Rect
+from physdes.vector2 import Vector2
 
 
 def test_polygon():
@@ -122,6 +129,137 @@ def test_polygon4():
     assert point_in_polygon(S, Point(qx, qy))
 
 
+def test_polygon_vertices():
+    triangle = [Point(0, 0), Point(4, 0), Point(0, 3)]
+    P = Polygon.try_new(triangle)
+    assert P.num_vertices() == 3
+    assert P.vertices() == triangle
+
+    square = [Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)]
+    Q = Polygon.try_new(square)
+    assert Q.num_vertices() == 4
+    assert Q.vertices() == square
+
+
+def test_polygon_edges():
+    square = [Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)]
+    P = Polygon(square)
+    edges = list(P.edges())
+    assert len(edges) == P.num_vertices()
+    assert edges[0] == (square[0], square[1])
+    assert edges[-1] == (square[-1], square[0])
+    assert P.perimeter() == 4
+    assert Polygon([Point(0, 0), Point(3, 0), Point(3, 4)]).perimeter() == 12
+
+
+def test_polygon_too_few_vertices():
+    with pytest.raises(PolygonError):
+        Polygon.try_new([Point(0, 0), Point(1, 1)])
+
+
+def test_polygon_signed_area():
+    square = [Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)]
+    assert Polygon(square).signed_area() == 1
+    assert Polygon(square).area() == 1
+
+    triangle = [Point(0, 0), Point(6, 0), Point(2, 5)]
+    P = Polygon(triangle)
+    Q = Polygon(list(reversed(triangle)))
+    assert P.signed_area() == 15
+    assert Q.signed_area() == -15
+    assert P.area() == Q.area()
+
+
+def test_polygon_contains_point():
+    coords = [(0, 0), (4, 0), (6, 3), (2, 6), (-2, 3)]
+    P = Polygon([Point(x, y) for x, y in coords])
+    assert P.contains_point(Point(2, 2))
+    assert P.contains_point(Point(2, 0))  # on an edge
+    assert P.contains_point(Point(5, 1.5))  # on a slanted edge
+    assert P.contains_point(Point(6, 3))  # on a vertex
+    assert P.contains_point(Point(0, 3))  # ray through a vertex
+    assert not P.contains_point(Point(-3, 3))  # ray through two vertices
+    assert not P.contains_point(Point(7, 3))
+    assert not P.contains_point(Point(2, 7))
+
+
+def test_polygon_bounding_box():
+    hgen = halton([2, 3], [11, 7])
+    S = create_test_polygon([Point(*hgen()) for _ in range(30)])
+    P = Polygon(S)
+    box = P.bounding_box()
+    xs = [p.x for p in S]
+    ys = [p.y for p in S]
+    assert box == Rect(Interval(min(xs), max(xs)), Interval(min(ys), max(ys)))
+    grid = [Point(x, y) for x in range(-500, 2600, 97) for y in range(-500, 2700, 89)]
+    outside = [q for q in grid if not box.contains(q)]
+    assert outside
+    for q in outside:
+        assert not P.contains_point(q)
+        assert P.contains_point(q) == point_in_polygon(S, q)
+    P += Vector2(1000, 1000)
+    assert P.bounding_box() == box.translate(Vector2(1000, 1000))
+    moved = P.vertices()
+    for q in grid:
+        assert P.contains_point(q) == point_in_polygon(moved, q)
+
+
+def test_polygon_simplify():
+    coords = [(0, 0), (2, 0), (4, 0), (4, 4), (4, 4), (2, 2), (0, 4)]
+    P = Polygon([Point(x, y) for x, y in coords])
+    Q = P.simplify()
+    kept = [(0, 0), (4, 0), (4, 4), (2, 2), (0, 4)]
+    assert Q.vertices() == [Point(x, y) for x, y in kept]  # (2, 2) is a corner
+    assert Q.signed_area_x2() == P.signed_area_x2()
+    spike = [Point(0, 0), Point(4, 0), Point(2, 0), Point(2, 2)]
+    assert Polygon(spike).simplify().num_vertices() == 4
+    triangle = Polygon([Point(0, 0), Point(4, 0), Point(0, 3)])
+    assert triangle.simplify().vertices() == triangle.vertices()
+
+
+def test_polygon_centroid():
+    square = [Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)]
+    assert Polygon(square).centroid() == Point(0.5, 0.5)
+
+    coords = [(0, 0), (2, 0), (2, 1), (1, 1), (1, 2), (0, 2)]
+    S = [Point(x, y) for x, y in coords]
+    c = Polygon(S).centroid()
+    assert c.x == pytest.approx(5 / 6)
+    assert c.y == pytest.approx(5 / 6)
+    avg = Point(sum(p.x for p in S) / len(S), sum(p.y for p in S) / len(S))
+    assert avg == Point(1, 1)
+    assert c != avg
+
+
+def test_convex_hull():
+    coords = [(1, 1), (0, 0), (4, 0), (2, 3), (4, 4), (0, 4), (2, 0), (4, 4), (3, 1)]
+    P = convex_hull([Point(x, y) for x, y in coords])
+    assert P.vertices() == [Point(0, 0), Point(4, 0), Point(4, 4), Point(0, 4)]
+    assert P.signed_area() > 0
+
+
+def test_convex_hull_degenerate():
+    collinear = [Point(2, 2), Point(0, 0), Point(3, 3), Point(1, 1)]
+    assert convex_hull(collinear).vertices() == [Point(0, 0), Point(3, 3)]
+    same = [Point(1, 2), Point(1, 2)]
+    assert convex_hull(same).vertices() == [Point(1, 2)]
+    pair = [Point(1, 2), Point(0, 5)]
+    assert convex_hull(pair).vertices() == [Point(0, 5), Point(1, 2)]
+
+
+def test_polygon_orientation():
+    cw = [Point(0, 0), Point(0, 1), Point(1, 1), Point(1, 0)]
+    P = Polygon(cw)
+    assert not P.is_counter_clockwise()
+    Q = P.into_counter_clockwise()
+    assert Q.is_counter_clockwise()
+    assert Q.vertices() == [Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)]
+    assert Q.area() == P.area()
+
+    R = Q.into_counter_clockwise()
+    assert R.vertices() == Q.vertices()
+
+
 # def test_polygon3():
 #     hgen = halton([2, 3], [11, 7])
 #     coords = [hgen() for _ in range(40)]
@@ -131,3 +269,21 @@ def test_polygon4():
 #         print("{},{}".format(p.x, p.y), end=' ')
 #     P = Polygon(S)
 #     assert P.signed_area_x2() == 3198528000
+
+
+def test_polygon_contains_points():
+    hgen = halton([2, 3], [11, 7])
+    S = create_test_polygon([Point(*hgen()) for _ in range(30)])
+    P = Polygon(S)
+    queries = [Point(x, y) for x in range(-10, 2060, 37) for y in range(-10, 2200, 41)]
+    queries += S  # vertices
+    queries += [Point((p.x + q.x) / 2, (p.y + q.y) / 2) for p, q in zip(S, S[1:])]
+    result = P.contains_points(queries)
+    assert result == [P.contains_point(q) for q in queries]
+    assert any(result) and not all(result)
+
+    coords = [(0, 0), (4, 0), (6, 3), (2, 6), (-2, 3)]
+    P = Polygon([Point(x, y) for x, y in coords])
+    queries = [Point(2, 2), Point(2, 0), Point(6, 3), Point(-3, 3), Point(7, 3)]
+    assert P.contains_points(queries) == [True, True, True, False, False]
+    assert P.contains_points([]) == []
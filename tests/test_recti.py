@@ -1,8 +1,24 @@
-from random import randint
+import io
+from concurrent.futures import ThreadPoolExecutor
+from random import randint, seed
 
+from physdes.generic import covered_area, min_dist
 from physdes.interval import Interval
 from physdes.point import Point
-from physdes.recti import Rect
+from physdes.recti import (
+    HSegment,
+    Rect,
+    Segment,
+    VSegment,
+    bounding_box,
+    connected_components,
+    find_overlapping_pairs,
+    par_bounding_box,
+    read_jsonl,
+    routing_channels,
+    write_jsonl,
+)
+from physdes.vector2 import Vector2
 
 
 class my_point(Point):
@@ -54,6 +70,401 @@ def test_Rectangle():
     # assert r2 in r1
 
 
+def test_Rectangle_translate():
+    r = Rect(Interval(4, 8), Interval(5, 7))
+    t = r.translate(Vector2(2, -3))
+    assert t.lb == Point(6, 2)
+    assert t.ub == Point(10, 4)
+    assert t.width() == r.width()
+    assert t.height() == r.height()
+
+
+def test_Rectangle_scale():
+    r = Rect(Interval(4, 8), Interval(5, 7))
+    s = r.scale(2)
+    assert s.width() == 2 * r.width()
+    assert s.height() == 2 * r.height()
+    assert s.lb == Point(8, 10)
+    assert s.ub == Point(16, 14)
+
+
+def test_Rectangle_inflate_deflate():
+    r = Rect(Interval(4, 8), Interval(5, 7))
+    big = r.inflate(2)
+    assert big == Rect(Interval(2, 10), Interval(3, 9))
+    assert big.deflate(2) == r
+    assert r.deflate(1) == Rect(Interval(5, 7), Interval(6, 6))
+    assert r.deflate(2) is None
+
+
+def test_Rectangle_to_polygon_oriented():
+    r = Rect(Interval(4, 8), Interval(5, 7))
+    cw = r.to_polygon_oriented(True)
+    ccw = r.to_polygon_oriented(False)
+    assert cw.vertices() != ccw.vertices()
+    assert not cw.is_counter_clockwise()
+    assert ccw.is_counter_clockwise()
+    assert cw.area() == ccw.area() == r.area()
+    for P in (cw, ccw):
+        assert all(r.contains(p) for p in P.vertices())
+        assert P.contains_point(Point(6, 6))
+
+
+def test_Rectangle_sort():
+    expected = [
+        Rect(Interval(0, 1), Interval(0, 5)),
+        Rect(Interval(0, 2), Interval(0, 1)),
+        Rect(Interval(0, 2), Interval(0, 3)),
+        Rect(Interval(0, 1), Interval(2, 3)),
+        Rect(Interval(4, 5), Interval(-1, 0)),
+    ]
+    shuffled = [expected[i] for i in (3, 0, 4, 2, 1)]
+    assert sorted(shuffled) == expected
+    shuffled.sort()
+    assert shuffled == expected
+    p = Point(1, 2)
+    for op in (Rect.__lt__, Rect.__le__, Rect.__gt__, Rect.__ge__):
+        assert op(expected[0], p) is NotImplemented
+
+
+def test_Rectangle_size_metrics():
+    r = Rect(Interval(1, 4), Interval(2, 6))
+    assert r.diagonal_length() == 5.0
+    assert r.manhattan_radius() == 3
+
+
+def test_Segment_crossing():
+    v = VSegment(5, Interval(0, 10))
+    h = HSegment(Interval(0, 10), 4)
+    assert v.intersection_with(h) == Point(5, 4)
+    assert h.intersection_with(v) == Point(5, 4)
+    assert v.min_dist_with(h) == 0
+    assert h.min_dist_with(v) == 0
+
+
+def test_Segment_near_miss():
+    v = VSegment(5, Interval(0, 10))
+    h = HSegment(Interval(7, 12), 13)
+    assert v.intersection_with(h) is None
+    assert h.intersection_with(v) is None
+    assert v.min_dist_with(h) == 5
+    assert h.min_dist_with(v) == 5
+
+
+def test_Segment_T_junction():
+    v = VSegment(5, Interval(0, 10))
+    h = HSegment(Interval(5, 12), 10)
+    assert v.intersection_with(h) == Point(5, 10)
+    assert h.intersection_with(v) == Point(5, 10)
+    assert v.min_dist_with(h) == 0
+
+
+def test_Segment_mixed():
+    route = [VSegment(5, Interval(0, 10)), HSegment(Interval(5, 12), 10)]
+    assert all(isinstance(s, Segment) for s in route)
+    assert [s.is_vertical() for s in route] == [True, False]
+    assert [s.length() for s in route] == [10, 7]
+
+    flipped = [s.flip() for s in route]
+    assert [s.is_vertical() for s in flipped] == [False, True]
+    assert [s.length() for s in flipped] == [10, 7]
+
+    assert route[0].contains(Point(5, 3))
+    assert not route[0].contains(Point(6, 3))
+    assert route[1].contains(Point(8, 10))
+    assert not route[1].contains(Point(13, 10))
+
+
+def test_connected_components():
+    rects = [
+        Rect(Interval(0, 2), Interval(0, 2)),
+        Rect(Interval(10, 12), Interval(10, 12)),
+        Rect(Interval(4, 6), Interval(1, 3)),
+        Rect(Interval(1, 4), Interval(2, 5)),
+    ]
+    assert connected_components(rects) == [[0, 2, 3], [1]]
+    assert connected_components([]) == []
+
+
+def test_jsonl():
+    rects = [
+        Rect(Interval(0, 2), Interval(0, 3)),
+        Rect(Interval(-5, 7), Interval(10, 11)),
+        Rect(Interval(1.5, 2.5), Interval(0, 0)),
+    ]
+    buf = io.StringIO()
+    write_jsonl(rects, buf)
+    lines = buf.getvalue().splitlines()
+    assert len(lines) == 3
+    assert list(read_jsonl(lines)) == rects
+    buf.seek(0)
+    assert list(read_jsonl(buf)) == rects
+
+
+def test_Rect_rotations():
+    a = Rect(Interval(3, 7), Interval(-2, 0))
+    b = a.rotate_90_ccw()
+    assert b == Rect(Interval(0, 2), Interval(3, 7))
+    assert b.width() == a.height()
+    assert b.height() == a.width()
+    assert a.rotate_90_cw() == Rect(Interval(-2, 0), Interval(-7, -3))
+    assert a.rotate_180() == Rect(Interval(-7, -3), Interval(0, 2))
+    assert b.rotate_90_ccw().rotate_90_ccw().rotate_90_ccw() == a
+    assert all(b.contains(p.rotate_90_ccw()) for p in (Point(3, -2), Point(7, 0)))
+
+
+def test_Rect_reflections_keep_type():
+    a = Rect(Interval(3, 7), Interval(-2, 0))
+    assert a.reflect_x() == Rect(Interval(3, 7), Interval(0, 2))
+    assert a.reflect_y() == Rect(Interval(-7, -3), Interval(-2, 0))
+    assert a.reflect_across_vertical(10) == Rect(Interval(13, 17), Interval(-2, 0))
+    assert a.reflect_across_horizontal(1) == Rect(Interval(3, 7), Interval(2, 4))
+    for r in (a.reflect_x(), a.reflect_y(), a.reflect_across_vertical(10)):
+        assert isinstance(r, Rect)
+    v = VSegment(5, Interval(1, 2))
+    assert isinstance(v.reflect_x(), VSegment)
+    assert v.reflect_across_vertical(0) == VSegment(-5, Interval(1, 2))
+    h = HSegment(Interval(1, 2), 5)
+    assert isinstance(h.reflect_y(), HSegment)
+    assert h.reflect_across_horizontal(0) == HSegment(Interval(1, 2), -5)
+
+
+def test_routing_channels():
+    region = Rect(Interval(0, 30), Interval(0, 10))
+    b1 = Rect(Interval(0, 10), Interval(0, 10))
+    b2 = Rect(Interval(20, 30), Interval(0, 10))
+    assert routing_channels([b1, b2], region) == [
+        Rect(Interval(10, 20), Interval(0, 10))
+    ]
+    assert routing_channels([], region) == [region]
+
+
+def test_routing_channels_partial():
+    region = Rect(Interval(0, 30), Interval(0, 20))
+    b1 = Rect(Interval(0, 10), Interval(0, 20))
+    b2 = Rect(Interval(20, 35), Interval(5, 15))
+    channels = routing_channels([b1, b2], region)
+    assert channels == [
+        Rect(Interval(10, 20), Interval(0, 20)),
+        Rect(Interval(20, 30), Interval(0, 5)),
+        Rect(Interval(20, 30), Interval(15, 20)),
+    ]
+    clipped = [b1, Rect(Interval(20, 30), Interval(5, 15))]
+    assert covered_area(channels + clipped) == region.area()
+    total = sum(c.area() for c in channels) + sum(b.area() for b in clipped)
+    assert total == region.area()
+
+
+def test_routing_channels_same_left_side():
+    region = Rect(Interval(0, 30), Interval(0, 20))
+    b1 = Rect(Interval(0, 10), Interval(8, 12))
+    b2 = Rect(Interval(10, 30), Interval(8, 20))
+    channels = routing_channels([b1, b2], region)
+    # both start at x = 0; the lower one is longer but still comes first
+    assert channels == [
+        Rect(Interval(0, 30), Interval(0, 8)),
+        Rect(Interval(0, 10), Interval(12, 20)),
+    ]
+    assert covered_area(channels + [b1, b2]) == region.area()
+
+
+def test_min_dist_symmetry():
+    r = Rect(Interval(0, 4), Interval(10, 14))
+    v = VSegment(7, Interval(2, 5))
+    h = HSegment(Interval(-3, 1), 6)
+    for p in (Point(2, 12), Point(4, 9), Point(-5, 20), Point(7, 3), Point(0, 6)):
+        for shape in (r, v, h):
+            assert p.min_dist_with(shape) == shape.min_dist_with(p)
+            assert min_dist(p, shape) == min_dist(shape, p)
+    assert Point(2, 12).min_dist_with(r) == 0  # inside
+    assert Point(4, 9).min_dist_with(r) == 1
+    assert Point(-5, 20).min_dist_with(r) == 11
+    assert Point(7, 3).min_dist_with(v) == 0  # on the segment
+    assert Point(0, 6).min_dist_with(v) == 8
+    assert Point(0, 6).min_dist_with(h) == 0
+
+
+def test_Rect_clip_segment():
+    r = Rect(Interval(0, 10), Interval(0, 10))
+    inside = VSegment(5, Interval(2, 7))
+    assert r.intersection_with(inside) == inside
+    v = r.intersection_with(VSegment(5, Interval(8, 15)))  # crosses the top
+    assert isinstance(v, VSegment)
+    assert v == VSegment(5, Interval(8, 10))
+    h = r.intersection_with(HSegment(Interval(-5, 5), 3))  # crosses the left
+    assert isinstance(h, HSegment)
+    assert h == HSegment(Interval(0, 5), 3)
+    assert r.intersection_with(HSegment(Interval(-5, 20), 10)) == HSegment(
+        Interval(0, 10), 10
+    )
+    assert r.intersection_with(VSegment(11, Interval(2, 7))) is None
+    assert r.intersection_with(VSegment(5, Interval(11, 17))) is None
+    assert r.intersection_with(HSegment(Interval(-5, -1), 3)) is None
+    s = Rect(Interval(5, 20), Interval(-5, 5))
+    assert r.intersection_with(s) == Rect(Interval(5, 10), Interval(0, 5))
+
+
+def test_Rect_iter_grid_points():
+    r = Rect(Interval(10, 12), Interval(-1, 2))  # 2 x 3
+    it = r.iter_grid_points()
+    assert len(it) == 12
+    pts = list(it)
+    assert len(pts) == 12
+    assert len(it) == 0
+    assert pts[:4] == [Point(10, -1), Point(11, -1), Point(12, -1), Point(10, 0)]
+    assert pts[-1] == Point(12, 2)
+    assert all(r.contains(p) for p in pts)
+    assert len(set((p.x, p.y) for p in pts)) == 12
+    assert list(Rect(Interval(4, 4), Interval(7, 7)).iter_grid_points()) == [
+        Point(4, 7)
+    ]
+
+
+def test_Rect_difference():
+    a = Rect(Interval(0, 10), Interval(0, 10))
+    hole = Rect(Interval(3, 6), Interval(4, 8))
+    pieces = a.difference(hole)
+    assert pieces == [
+        Rect(Interval(0, 10), Interval(8, 10)),
+        Rect(Interval(0, 10), Interval(0, 4)),
+        Rect(Interval(0, 3), Interval(4, 8)),
+        Rect(Interval(6, 10), Interval(4, 8)),
+    ]
+    assert sum(r.area() for r in pieces) == a.area() - hole.area()
+    assert covered_area(pieces + [hole]) == a.area()
+
+    edge = Rect(Interval(7, 12), Interval(-2, 12))  # covers the right edge
+    assert a.difference(edge) == [Rect(Interval(0, 7), Interval(0, 10))]
+    corner = Rect(Interval(5, 15), Interval(5, 15))
+    assert len(a.difference(corner)) == 2
+    assert sum(r.area() for r in a.difference(corner)) == 75
+
+    far = Rect(Interval(20, 30), Interval(0, 10))
+    assert a.difference(far) == [a]
+    touching = Rect(Interval(10, 30), Interval(0, 10))
+    assert a.difference(touching) == [a]
+    assert a.difference(Rect(Interval(-1, 11), Interval(0, 10))) == []
+    assert a.difference(a) == []
+
+
+def test_Rect_overlap_area():
+    a = Rect(Interval(0, 4), Interval(0, 4))
+    assert a.overlap_area(Rect(Interval(2, 6), Interval(2, 6))) == 4
+    assert a.overlap_area(Rect(Interval(4, 6), Interval(0, 4))) == 0  # touching
+    assert a.overlap_area(Rect(Interval(5, 6), Interval(0, 4))) == 0  # disjoint
+    inner = Rect(Interval(1, 3), Interval(1, 2))
+    assert a.overlap_area(inner) == inner.area() == 2
+    assert inner.overlap_area(a) == 2
+    assert a.overlap_area(a) == a.area()
+
+
+def test_par_bounding_box():
+    rects = []
+    for _ in range(200):
+        x, y = randint(-1000, 1000), randint(-1000, 1000)
+        rects.append(Rect(Interval(x, x + randint(0, 100)), Interval(y, y + 7)))
+    seq = bounding_box(rects)
+    assert all(seq.contains(r) for r in rects)
+    with ThreadPoolExecutor(max_workers=4) as pool:
+        assert par_bounding_box(rects, pool) == seq
+        assert par_bounding_box(rects, pool, nchunks=7) == seq
+        assert par_bounding_box(rects[:3], pool, nchunks=16) == bounding_box(rects[:3])
+        assert par_bounding_box([], pool) is None
+    assert bounding_box([]) is None
+
+
+def test_Rect_bounding_of_points():
+    pts = [Point(5, -1), Point(-3, 7), Point(2, 2), Point(0, -4), Point(9, 0)]
+    box = Rect.bounding_of_points(pts)
+    assert box == Rect(Interval(-3, 9), Interval(-4, 7))
+    assert all(box.contains(p) for p in pts)
+    single = Rect.bounding_of_points([Point(2, 3)])
+    assert single == Rect(Interval(2, 2), Interval(3, 3))
+    assert single.area() == 0
+    assert Rect.bounding_of_points([]) is None
+    assert Rect.bounding_of_points(iter(pts)) == box
+
+
+def test_Rect_segment_relations():
+    a = Rect(Interval(0, 10), Interval(0, 10))
+    inside = [VSegment(5, Interval(2, 8)), HSegment(Interval(0, 10), 10)]
+    straddling = [VSegment(5, Interval(8, 12)), HSegment(Interval(-3, 4), 7)]
+    outside = [VSegment(12, Interval(2, 8)), HSegment(Interval(2, 8), -1)]
+    for s in inside:
+        assert a.contains(s)
+        assert a.overlaps(s) and s.overlaps(a)
+    for s in straddling:
+        assert not a.contains(s)
+        assert a.overlaps(s) and s.overlaps(a)
+    for s in outside:
+        assert not a.contains(s)
+        assert not a.overlaps(s) and not s.overlaps(a)
+
+
+def test_find_overlapping_pairs():
+    seed(42)
+    rects = []
+    for _ in range(200):
+        x, y = randint(0, 1000), randint(0, 1000)
+        rects.append(Rect(Interval(x, x + randint(0, 60)), Interval(y, y + 40)))
+    pairs = find_overlapping_pairs(rects)
+    brute = [
+        (i, j)
+        for i in range(len(rects))
+        for j in range(i + 1, len(rects))
+        if rects[i].overlaps(rects[j])
+    ]
+    assert pairs == brute
+    assert len(set(pairs)) == len(pairs)
+    assert find_overlapping_pairs([]) == []
+
+
+class _Counted(int):
+    """An integer that counts how often it is ordered against another"""
+
+    count = 0
+
+    def __lt__(self, other):
+        _Counted.count += 1
+        return int.__lt__(self, other)
+
+    def __le__(self, other):
+        _Counted.count += 1
+        return int.__le__(self, other)
+
+    def __gt__(self, other):
+        _Counted.count += 1
+        return int.__gt__(self, other)
+
+    def __ge__(self, other):
+        _Counted.count += 1
+        return int.__ge__(self, other)
+
+
+def test_find_overlapping_pairs_comparisons():
+    def comparisons(n):
+        # stacked strips: all are active at once, yet none overlap
+        c = _Counted
+        rects = [
+            Rect(Interval(c(i), c(i + n)), Interval(c(2 * i), c(2 * i + 1)))
+            for i in range(n)
+        ]
+        _Counted.count = 0
+        assert find_overlapping_pairs(rects) == []
+        return _Counted.count
+
+    small, large = comparisons(256), comparisons(1024)
+    assert large < 6 * small  # a quadratic scan would be 16 times
+    assert large < 50 * 1024
+
+
+def test_Rect_unit():
+    u = Rect.unit()
+    assert u == Rect(Interval(0, 1), Interval(0, 1))
+    assert u.area() == 1
+    assert u.contains(Point.origin())
+
+
 def test_Rectilinear():
     N = 20
     lst = []
@@ -0,0 +1,108 @@
+from physdes.halton_int import halton
+from physdes.interval import Interval
+from physdes.point import Point
+from physdes.recti import HSegment, Rect, VSegment
+from physdes.router import GridRouter, Router, route_l_shaped, route_z_shaped
+
+
+def test_order_nets():
+    nets = [
+        (Point(0, 0), Point(10, 10)),
+        (Point(5, 5), Point(4, 3)),
+        (Point(0, 0), Point(0, 8)),
+        (Point(3, 3), Point(0, 0)),
+    ]
+    order = Router().order_nets(nets)
+    assert order[0] == 1
+    assert order == [1, 3, 2, 0]
+    assert Router().order_nets([]) == []
+
+
+def test_route_l_shaped():
+    src, dst = Point(1, 7), Point(6, 2)
+    routes = route_l_shaped(src, dst)
+    assert len(routes) == 2
+    for route in routes:
+        assert sum(s.length() for s in route) == src.min_dist_with(dst)
+        assert route[0].contains(src)
+        assert route[-1].contains(dst)
+        assert route[0].intersection_with(route[1]) is not None
+    assert routes[0][0].is_vertical() != routes[1][0].is_vertical()
+
+
+def test_route_l_shaped_degenerate():
+    routes = route_l_shaped(Point(1, 7), Point(1, 2))
+    assert len(routes) == 1
+    assert len(routes[0]) == 1
+    assert routes[0][0] == VSegment(1, Interval(2, 7))
+    routes = route_l_shaped(Point(4, 2), Point(1, 2))
+    assert routes == [[HSegment(Interval(1, 4), 2)]]
+
+
+def test_route_z_shaped():
+    src, dst = Point(0, 0), Point(6, 4)
+    route = route_z_shaped(src, dst, 2)
+    assert len(route) == 3
+    assert sum(s.length() for s in route) == src.min_dist_with(dst)
+    assert route[0].contains(src)
+    assert route[-1].contains(dst)
+    assert len(route_z_shaped(src, dst, 6)) == 2
+
+
+def _is_connected_path(path):
+    return all(p.min_dist_with(q) == 1 for p, q in zip(path, path[1:]))
+
+
+def test_GridRouter_open():
+    R = GridRouter(Rect(Interval(0, 9), Interval(0, 9)))
+    path = R.route(Point(1, 2), Point(7, 5))
+    assert path[0] == Point(1, 2)
+    assert path[-1] == Point(7, 5)
+    assert len(path) - 1 == 9
+    assert _is_connected_path(path)
+    assert R.route(Point(3, 3), Point(3, 3)) == [Point(3, 3)]
+
+
+def test_GridRouter_wall():
+    wall = [Point(4, y) for y in range(0, 9)]
+    R = GridRouter(Rect(Interval(0, 9), Interval(0, 9)), wall)
+    path = R.route(Point(2, 0), Point(6, 0))
+    assert len(path) - 1 == 4 + 2 * 9
+    assert _is_connected_path(path)
+    assert not any(p in wall for p in path)
+
+
+def test_GridRouter_walled_off():
+    wall = [Point(4, y) for y in range(0, 10)]
+    R = GridRouter(Rect(Interval(0, 9), Interval(0, 9)), wall)
+    assert R.route(Point(2, 0), Point(6, 0)) is None
+    assert R.route(Point(2, 0), Point(4, 0)) is None
+
+
+def test_GridRouter_astar():
+    hgen = halton([2, 3], [4, 3])
+    blocked = [Point(x, y) for x, y in (hgen() for _ in range(60))]
+    blocked = [p for p in blocked if p != Point(0, 0) and p != Point(15, 20)]
+    R = GridRouter(Rect(Interval(0, 15), Interval(0, 26)), blocked)
+    lee = R.route(Point(0, 0), Point(15, 20))
+    path, stats = R.route_astar(Point(0, 0), Point(15, 20))
+    assert len(path) == len(lee)
+    assert path[0] == Point(0, 0)
+    assert path[-1] == Point(15, 20)
+    assert _is_connected_path(path)
+    assert not any(p in blocked for p in path)
+    assert 0 < stats.expanded <= 16 * 27
+
+    path2, stats2 = R.route_astar(Point(0, 0), Point(15, 20))
+    assert path2 == path
+    assert stats2 == stats
+
+
+def test_GridRouter_astar_degenerate():
+    wall = [Point(4, y) for y in range(0, 10)]
+    R = GridRouter(Rect(Interval(0, 9), Interval(0, 9)), wall)
+    path, _ = R.route_astar(Point(2, 0), Point(6, 0))
+    assert path is None
+    path, stats = R.route_astar(Point(2, 2), Point(2, 2))
+    assert path == [Point(2, 2)]
+    assert stats.expanded == 1
@@ -1,11 +1,20 @@
+import pytest
+
+from physdes.generic import covered_area
 from physdes.halton_int import halton
+from physdes.interval import Interval
 from physdes.point import Point
+from physdes.polygon import Polygon
+from physdes.recti import Rect
 from physdes.rpolygon import (
     RPolygon,
+    RPolygonBuilder,
+    RPolygonError,
     create_test_rpolygon,
     create_xmono_rpolygon,
     create_ymono_rpolygon,
     point_in_rpolygon,
+    union_rectangles,
 )
 
 
@@ -101,3 +110,191 @@ def test_RPolygon5():
     P = RPolygon(S)
     assert P.signed_area() == -2176416
     assert point_in_rpolygon(S, Point(qx, qy))
+
+
+def test_RPolygon_snap():
+    coords = [(0, 0), (10, 0), (10, 5), (5, 5), (6, 10), (0, 10)]
+    P = RPolygon([Point(x, y) for x, y in coords])
+    Q = P.snap_to_rectilinear(1)
+    assert Q is not None
+    assert Q.signed_area() == 75
+    assert P.snap_to_rectilinear(0) is None
+
+
+def test_RPolygon_snap_diagonal():
+    coords = [(0, 0), (10, 0), (10, 5), (5, 10), (0, 10)]
+    P = RPolygon([Point(x, y) for x, y in coords])
+    assert P.snap_to_rectilinear(1) is None
+
+
+def test_RPolygon_try_new():
+    coords = [(0, 0), (3, 0), (3, 1), (2, 1), (2, 2), (1, 2), (1, 3), (0, 3)]
+    S = [Point(x, y) for x, y in coords]
+    P = RPolygon.try_new(S)
+    assert P.num_vertices() == 8
+    assert P.vertices() == S
+    assert P.signed_area() == 6
+
+
+def test_RPolygon_try_new_diagonal():
+    coords = [(0, 0), (3, 0), (3, 1), (1, 3), (0, 3)]
+    with pytest.raises(RPolygonError) as excinfo:
+        RPolygon.try_new([Point(x, y) for x, y in coords])
+    assert excinfo.value.edge == 2
+
+
+def test_RPolygon_try_new_consecutive_horizontal():
+    coords = [(0, 0), (1, 0), (3, 0), (3, 2), (0, 2)]
+    with pytest.raises(RPolygonError) as excinfo:
+        RPolygon.try_new([Point(x, y) for x, y in coords])
+    assert excinfo.value.edge == 1
+
+
+def test_RPolygonBuilder():
+    P = (
+        RPolygonBuilder.start(Point(1, 1))
+        .go_right(4)
+        .go_up(2)
+        .go_left(2)
+        .go_up(3)
+        .go_left(2)
+        .go_down(5)
+        .build()
+    )
+    coords = [(1, 1), (5, 1), (5, 3), (3, 3), (3, 6), (1, 6)]
+    Q = RPolygon.try_new([Point(x, y) for x, y in coords])
+    assert P.vertices() == Q.vertices()
+    assert P.signed_area() == Q.signed_area() == 14
+
+
+def test_RPolygonBuilder_not_closed():
+    builder = RPolygonBuilder.start(Point(0, 0)).go_right(3).go_up(2).go_left(3)
+    with pytest.raises(RPolygonError) as excinfo:
+        builder.build()
+    assert excinfo.value.edge == 2
+
+
+def test_RPolygon_edges():
+    coords = [(0, 0), (3, 0), (3, 1), (1, 1), (1, 4), (0, 4)]
+    P = RPolygon([Point(x, y) for x, y in coords])
+    edges = list(P.edges())
+    assert len(edges) == P.num_vertices()
+    assert [e.is_vertical() for e in edges] == [False, True] * 3
+    assert P.perimeter() == 14
+    square = [Point(0, 0), Point(1, 0), Point(1, 1), Point(0, 1)]
+    assert RPolygon(square).perimeter() == 4
+
+
+def test_RPolygon_simplify():
+    coords = [(0, 0), (1, 0), (3, 0), (3, 1), (1, 1), (1, 1), (1, 2), (1, 4), (0, 4)]
+    S = [Point(x, y) for x, y in coords]
+    with pytest.raises(RPolygonError):
+        RPolygon.try_new(S)
+    Q = RPolygon(S).simplify()
+    corners = [(0, 0), (3, 0), (3, 1), (1, 1), (1, 4), (0, 4)]
+    assert Q.vertices() == [Point(x, y) for x, y in corners]
+    assert RPolygon.try_new(Q.vertices()).signed_area() == 6
+
+    hgen = halton([3, 2], [7, 11])
+    S = create_test_rpolygon([Point(*hgen()) for _ in range(50)])
+    P = RPolygon(S)
+    assert P.simplify().signed_area() == P.signed_area()
+
+
+def test_RPolygon_area():
+    shapes = [
+        [(0, 0), (4, 0), (4, 3), (0, 3)],
+        [(0, 0), (3, 0), (3, 1), (1, 1), (1, 4), (0, 4)],
+        [
+            (1, 0),
+            (2, 0),
+            (2, 1),
+            (3, 1),
+            (3, 2),
+            (2, 2),
+            (2, 3),
+            (1, 3),
+            (1, 2),
+            (0, 2),
+            (0, 1),
+            (1, 1),
+        ],
+    ]
+    expected = [12, 6, 5]
+    for coords, area in zip(shapes, expected):
+        S = [Point(x, y) for x, y in coords]
+        assert RPolygon(S).area() == area
+        assert RPolygon(S).area() == Polygon(S).area()
+        S.reverse()
+        assert RPolygon(S).area() == area
+
+
+def test_RPolygon_to_rectangles():
+    L_shape = [(0, 0), (3, 0), (3, 1), (1, 1), (1, 4), (0, 4)]
+    plus = [
+        (1, 0),
+        (2, 0),
+        (2, 1),
+        (3, 1),
+        (3, 2),
+        (2, 2),
+        (2, 3),
+        (1, 3),
+        (1, 2),
+        (0, 2),
+        (0, 1),
+        (1, 1),
+    ]
+    for coords, count in [(L_shape, 2), (plus, 3)]:
+        P = RPolygon([Point(x, y) for x, y in coords])
+        rects = P.to_rectangles()
+        assert len(rects) == count
+        assert sum(r.area() for r in rects) == P.area()
+        assert covered_area(rects) == P.area()  # no overlap
+
+
+def test_RPolygon_to_rectangles2():
+    hgen = halton([3, 2], [7, 11])
+    coords = [hgen() for _ in range(50)]
+    S = create_test_rpolygon([Point(x, y) for x, y in coords])
+    P = RPolygon(S)
+    rects = P.to_rectangles()
+    assert sum(r.area() for r in rects) == P.area()
+
+
+def test_RPolygon_contains_point():
+    coords = [(0, 0), (3, 0), (3, 1), (2, 1), (2, 2), (1, 2), (1, 3), (0, 3)]
+    P = RPolygon([Point(x, y) for x, y in coords])
+    assert not P.contains_point(Point(2.5, 2.5))  # in the notch
+    assert not P.contains_point(Point(2, 3))
+    assert P.contains_point(Point(3, 0.5))  # on a vertical edge
+    assert P.contains_point(Point(1.5, 2))  # on a horizontal edge
+    assert P.contains_point(Point(2, 2))  # on a vertex
+    assert P.contains_point(Point(0.5, 0.5))
+    assert P.contains_point(Point(1.5, 1.5))
+    assert not P.contains_point(Point(4, 0.5))
+
+
+def test_union_rectangles():
+    r1 = Rect(Interval(0, 4), Interval(0, 2))
+    r2 = Rect(Interval(2, 6), Interval(1, 3))
+    [P] = union_rectangles([r1, r2])
+    assert P.num_vertices() <= 8
+    assert P.signed_area() == covered_area([r1, r2]) == 14
+    assert RPolygon.try_new(P.vertices()).num_vertices() == P.num_vertices()
+
+
+def test_union_rectangles_disjoint():
+    r1 = Rect(Interval(0, 1), Interval(0, 1))
+    r2 = Rect(Interval(3, 5), Interval(0, 2))
+    Ps = union_rectangles([r2, r1])
+    assert len(Ps) == 2
+    assert [P.signed_area() for P in Ps] == [1, 4]
+
+
+def test_union_rectangles_nested():
+    outer = Rect(Interval(0, 10), Interval(0, 10))
+    inner = Rect(Interval(2, 5), Interval(3, 4))
+    [P] = union_rectangles([inner, outer])
+    coords = [(0, 0), (10, 0), (10, 10), (0, 10)]
+    assert P.vertices() == [Point(x, y) for x, y in coords]
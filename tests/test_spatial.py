@@ -0,0 +1,119 @@
+from random import randint, seed, uniform
+
+from physdes.interval import Interval
+from physdes.point import Point
+from physdes.recti import Rect
+from physdes.spatial import GridIndex, QuadTree, RTree, nearest_point_accelerated
+
+
+def _nearest_brute_force(query, points):
+    return min(range(len(points)), key=lambda i: (query.min_dist_with(points[i]), i))
+
+
+def test_nearest_point_accelerated():
+    seed(5)
+    points = [Point(randint(0, 1000), randint(0, 1000)) for _ in range(1000)]
+    index = GridIndex(points, 32)
+    for _ in range(200):
+        query = Point(randint(-200, 1200), randint(-200, 1200))
+        assert nearest_point_accelerated(query, index) == _nearest_brute_force(
+            query, points
+        )
+
+
+def test_nearest_point_accelerated_sparse():
+    points = [Point(-50, 3), Point(400, 400)]
+    index = GridIndex(points, 10)
+    assert nearest_point_accelerated(Point(0, 0), index) == 0
+    assert nearest_point_accelerated(Point(300, 300), index) == 1
+    assert nearest_point_accelerated(Point(0, 0), GridIndex([], 10)) is None
+
+
+def test_nearest_point_accelerated_float():
+    index = GridIndex([Point(0, 0), Point(3, 4), Point(7, 1)], 2)
+    assert nearest_point_accelerated(Point(1.5, 2.5), index) == 1
+    index = GridIndex([Point(0, 0), Point(3, 4), Point(7, 1)], 2.5)
+    assert nearest_point_accelerated(Point(6, 2), index) == 2
+    seed(7)
+    points = [Point(uniform(-50, 50), uniform(-50, 50)) for _ in range(300)]
+    index = GridIndex(points, 3.7)
+    for _ in range(100):
+        query = Point(uniform(-80, 80), uniform(-80, 80))
+        assert nearest_point_accelerated(query, index) == _nearest_brute_force(
+            query, points
+        )
+
+
+def test_quadtree():
+    seed(11)
+    rects = {}
+    qt = QuadTree(Rect(Interval(0, 1000), Interval(0, 1000)), capacity=4)
+    for i in range(300):
+        x, y = randint(0, 990), randint(0, 990)
+        w, h = randint(0, 40), randint(0, 40)
+        rects[i] = Rect(Interval(x, x + w), Interval(y, y + h))
+        qt.insert(i, rects[i])
+    assert len(qt) == 300
+
+    def brute_force(area):
+        return sorted(i for i, r in rects.items() if r.overlaps(area))
+
+    area = Rect(Interval(200, 450), Interval(300, 600))
+    found = qt.query(area)
+    assert found == brute_force(area)
+    assert found
+
+    for i in found[::2]:
+        assert qt.remove(i)
+        del rects[i]
+    assert not qt.remove(found[0])
+    assert found[0] not in qt
+    assert qt.query(area) == brute_force(area)
+    assert all(i not in qt.query(area) for i in found[::2])
+
+    for i in range(0, 300, 7):
+        if i in rects:
+            qt.remove(i)
+            del rects[i]
+    for area in (
+        Rect(Interval(0, 1000), Interval(0, 1000)),
+        Rect(Interval(500, 500), Interval(0, 1000)),
+        Rect(Interval(-10, -5), Interval(-10, -5)),
+    ):
+        assert qt.query(area) == brute_force(area)
+
+
+def test_quadtree_outside_and_replace():
+    qt = QuadTree(Rect(Interval(0, 10), Interval(0, 10)), capacity=1)
+    qt.insert(0, Rect(Interval(-5, 2), Interval(1, 2)))
+    qt.insert(1, Rect(Interval(1, 2), Interval(1, 2)))
+    qt.insert(2, Rect(Interval(7, 8), Interval(7, 8)))
+    assert qt.query(Rect(Interval(-5, -4), Interval(0, 10))) == [0]
+    qt.insert(1, Rect(Interval(8, 9), Interval(8, 9)))
+    assert len(qt) == 3
+    assert qt.query(Rect(Interval(0, 3), Interval(0, 3))) == [0]
+    assert qt.query(Rect(Interval(6, 10), Interval(6, 10))) == [1, 2]
+
+
+def test_rtree():
+    seed(19)
+    rects = []
+    for _ in range(200):
+        x, y = randint(0, 1000), randint(0, 1000)
+        w, h = randint(0, 80), randint(0, 80)
+        rects.append(Rect(Interval(x, x + w), Interval(y, y + h)))
+    tree = RTree(rects)
+    assert len(tree) == 200
+    for _ in range(100):
+        x, y = randint(-50, 1050), randint(-50, 1050)
+        query = Rect(Interval(x, x + randint(0, 200)), Interval(y, y + randint(0, 200)))
+        expected = [i for i, r in enumerate(rects) if r.overlaps(query)]
+        assert tree.query_overlapping(query) == expected
+
+
+def test_rtree_small():
+    assert RTree([]).query_overlapping(Rect(Interval(0, 1), Interval(0, 1))) == []
+    r = Rect(Interval(0, 1), Interval(0, 1))
+    tree = RTree([r, r, r], capacity=2)
+    assert tree.query_overlapping(Rect(Interval(1, 2), Interval(1, 2))) == [0, 1, 2]
+    assert tree.query_overlapping(Rect(Interval(2, 3), Interval(0, 1))) == []
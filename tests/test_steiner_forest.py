@@ -0,0 +1,203 @@
+from random import randint, seed
+
+from physdes.interval import Interval
+from physdes.point import Point
+from physdes.recti import Rect
+from physdes.steiner_forest import (
+    build_forest,
+    hanan_grid,
+    manhattan_mst,
+    rectilinear_steiner_tree,
+    steiner_tree_avoiding,
+    wirelength,
+)
+
+
+def _mst_length(points):
+    n = len(points)
+    dist = [points[0].min_dist_with(p) for p in points]
+    done = [False] * n
+    done[0] = True
+    total = 0
+    for _ in range(n - 1):
+        j = min((i for i in range(n) if not done[i]), key=lambda i: dist[i])
+        done[j] = True
+        total += dist[j]
+        for i in range(n):
+            dist[i] = min(dist[i], points[j].min_dist_with(points[i]))
+    return total
+
+
+def _connects_all(tree, points):
+    """All points lie on the tree and the tree forms a single component"""
+    if not all(any(s.contains(p) for s in tree) for p in points):
+        return False
+    seen = {0}
+    stack = [0]
+    while stack:
+        i = stack.pop()
+        for j, s in enumerate(tree):
+            if j not in seen and tree[i].overlaps(s):
+                seen.add(j)
+                stack.append(j)
+    return len(seen) == len(tree)
+
+
+def test_rectilinear_steiner_tree_3_points():
+    pts = [Point(0, 0), Point(4, 1), Point(1, 4)]
+    tree = rectilinear_steiner_tree(pts)
+    assert wirelength(tree) == 8  # half perimeter of the bounding box
+    assert _connects_all(tree, pts)
+
+
+def test_rectilinear_steiner_tree_4_points():
+    pts = [Point(0, 1), Point(1, 0), Point(2, 1), Point(1, 2)]
+    tree = rectilinear_steiner_tree(pts)
+    assert wirelength(tree) == 4  # the cross, MST would take 6
+    assert _connects_all(tree, pts)
+
+
+def test_rectilinear_steiner_tree_collinear():
+    pts = [Point(3, 5), Point(0, 5), Point(7, 5)]
+    tree = rectilinear_steiner_tree(pts)
+    assert wirelength(tree) == 7
+    assert len(tree) == 1
+    assert _connects_all(tree, pts)
+
+
+def test_rectilinear_steiner_tree_degenerate():
+    assert rectilinear_steiner_tree([]) == []
+    assert rectilinear_steiner_tree([Point(1, 1), Point(1, 1)]) == []
+
+
+def test_rectilinear_steiner_tree_random():
+    seed(7)
+    for _ in range(20):
+        pts = [Point(randint(0, 30), randint(0, 30)) for _ in range(8)]
+        tree = rectilinear_steiner_tree(pts)
+        assert _connects_all(tree, pts)
+        assert wirelength(tree) <= _mst_length(pts)
+
+
+def test_hanan_grid():
+    pts = [Point(0, 0), Point(4, 1), Point(1, 4)]
+    grid = hanan_grid(pts)
+    assert len(grid) == 9
+    assert all(p in grid for p in pts)
+    assert Point(1, 1) in grid
+    assert Point(4, 4) in grid
+
+
+def test_hanan_grid_collinear():
+    pts = [Point(3, 5), Point(0, 5), Point(7, 5), Point(3, 5)]
+    assert hanan_grid(pts) == [Point(0, 5), Point(3, 5), Point(7, 5)]
+    assert hanan_grid([]) == []
+
+
+def test_manhattan_mst_square():
+    pts = [Point(0, 0), Point(5, 0), Point(0, 5), Point(5, 5)]
+    edges = manhattan_mst(pts)
+    assert len(edges) == 3
+    assert sum(w for _, _, w in edges) == 15
+    assert all(w == pts[i].min_dist_with(pts[j]) for i, j, w in edges)
+
+
+def test_manhattan_mst_random():
+    seed(3)
+    for n in (1, 2, 5, 12, 40):
+        for _ in range(10):
+            pts = [Point(randint(-20, 20), randint(-20, 20)) for _ in range(n)]
+            edges = manhattan_mst(pts)
+            assert len(edges) == n - 1
+            assert sum(w for _, _, w in edges) == _mst_length(pts)
+
+
+def _touches(tree_a, tree_b):
+    return any(s.overlaps(t) for s in tree_a for t in tree_b)
+
+
+def test_build_forest_crossing_nets():
+    net_a = [Point(0, 3), Point(6, 3), Point(3, 6)]
+    net_b = [Point(2, 0), Point(4, 5), Point(8, 1)]
+    trees, stats = build_forest([net_a, net_b])
+    assert len(trees) == 2
+    assert _connects_all(trees[0], net_a)
+    assert _connects_all(trees[1], net_b)
+    # the independent trees would cross at (4, 3); net B goes around net A
+    assert _touches(rectilinear_steiner_tree(net_a), rectilinear_steiner_tree(net_b))
+    assert not _touches(trees[0], trees[1])
+    assert stats.per_net[0] == wirelength(rectilinear_steiner_tree(net_a)) == 9
+    assert stats.per_net[1] > wirelength(rectilinear_steiner_tree(net_b))
+    assert stats.total == sum(stats.per_net) == 24
+    assert stats.unrouted == []
+
+
+def test_build_forest_pin_on_wire():
+    net_a = [Point(0, 0), Point(4, 0)]
+    net_b = [Point(2, 0), Point(2, 4)]  # a pin on the straight wire of net A
+    net_c = [Point(0, 6), Point(4, 8)]
+    trees, stats = build_forest([net_a, net_b, net_c])
+    assert stats.unrouted == []
+    for tree, net in zip(trees, [net_a, net_b, net_c]):
+        assert _connects_all(tree, net)
+    for i in range(3):
+        for j in range(i + 1, 3):
+            assert not _touches(trees[i], trees[j])
+    assert stats.per_net == [6, 4, 6]
+
+
+def test_build_forest_unroutable():
+    net_a = [Point(0, 0), Point(4, 0)]
+    trees, stats = build_forest([net_a, [Point(4, 0), Point(4, 4)]])
+    assert trees == [None, None]  # a pin shared by both nets
+    assert stats.unrouted == [0, 1] and stats.total == 0
+    diamond = [Point(0, 0), Point(1, 1), Point(2, 0), Point(1, -1)]
+    inner = [Point(1, 0), Point(5, 0)]  # hemmed in by the pins of the diamond
+    trees, stats = build_forest([diamond, inner])
+    assert stats.unrouted == [1]
+    assert _connects_all(trees[0], diamond)
+    assert not any(s.contains(Point(1, 0)) for s in trees[0])
+
+
+def test_build_forest_empty():
+    trees, stats = build_forest([])
+    assert trees == [] and stats.total == 0 and stats.unrouted == []
+
+
+def test_steiner_tree_avoiding_detour():
+    pins = [Point(0, 0), Point(10, 0), Point(10, 8)]
+    wall = Rect(Interval(4, 6), Interval(-3, 3))
+    tree = steiner_tree_avoiding(pins, [wall])
+    assert _connects_all(tree, pins)
+    assert not any(s.overlaps(wall) for s in tree)
+    assert wirelength(tree) > wirelength(rectilinear_steiner_tree(pins))
+    assert wirelength(steiner_tree_avoiding(pins, [])) == 18
+
+
+def test_steiner_tree_avoiding_unreachable():
+    pins = [Point(0, 0), Point(10, 10)]
+    ring = [
+        Rect(Interval(8, 12), Interval(7, 8)),
+        Rect(Interval(8, 12), Interval(12, 13)),
+        Rect(Interval(7, 8), Interval(7, 13)),
+        Rect(Interval(12, 13), Interval(7, 13)),
+    ]
+    assert steiner_tree_avoiding(pins, ring) is None
+    assert steiner_tree_avoiding(pins, ring[:3]) is not None
+    inside = Rect(Interval(-1, 1), Interval(-1, 1))
+    assert steiner_tree_avoiding(pins, [inside]) is None
+
+
+def test_steiner_tree_avoiding_large_and_float():
+    pins = [Point(0, 0), Point(3 * 10**6, 0)]
+    macro = Rect(Interval(10**6, 2 * 10**6), Interval(-(10**6), 10**6))
+    tree = steiner_tree_avoiding(pins, [macro])
+    assert _connects_all(tree, pins)
+    assert not any(s.overlaps(macro) for s in tree)
+    assert wirelength(tree) == 3 * 10**6 + 2 * (10**6 + 1)
+    pins = [Point(0.5, 0.0), Point(5.5, 0.0)]
+    wall = Rect(Interval(2.25, 3.75), Interval(-2.5, 2.5))
+    tree = steiner_tree_avoiding(pins, [wall])
+    assert _connects_all(tree, pins)
+    assert not any(s.overlaps(wall) for s in tree)
+    assert wirelength(tree) == 5.0 + 2 * 3.5
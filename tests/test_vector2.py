@@ -1,3 +1,5 @@
+import pytest
+
 from physdes.vector2 import Vector2
 
 
@@ -26,3 +28,88 @@ def test_Vector2():
 
     r = Vector2(-b, c)
     assert (p + q) + r == p + (q + r)
+
+
+def test_clamp_magnitude():
+    v = Vector2(30.0, -40.0)
+    w = v.clamp_magnitude(10.0)
+    assert w == Vector2(6.0, -8.0)
+    assert v == Vector2(30.0, -40.0)
+    assert Vector2(3, 4).clamp_magnitude(10.0) == Vector2(3, 4)
+    assert Vector2(3, 4).clamp_magnitude(5.0) == Vector2(3, 4)
+
+
+def test_magnitude_normalized():
+    assert Vector2(3.0, 4.0).magnitude() == 5.0
+    assert Vector2(-5, 12).magnitude() == 13.0
+    u = Vector2(3.0, 4.0).normalized()
+    assert u.magnitude() == pytest.approx(1.0)
+    assert u.x == pytest.approx(0.6)
+    assert u.y == pytest.approx(0.8)
+    assert Vector2(-7, 2).normalized().magnitude() == pytest.approx(1.0)
+    assert Vector2(0, 0).normalized() == Vector2(0.0, 0.0)
+
+
+def test_perp_rotations():
+    v = Vector2(3, -7)
+    p = v.perp()
+    assert p == Vector2(7, 3)
+    assert v.x * p.x + v.y * p.y == 0
+    assert v.cross(p) > 0  # p is on the left of v
+    assert v.rotate_90_ccw() == p
+    assert v.rotate_90_cw() == -p
+    assert v.rotate_90_ccw().rotate_90_ccw().rotate_90_ccw().rotate_90_ccw() == v
+    assert v.rotate_90_cw().rotate_90_cw().rotate_90_cw().rotate_90_cw() == v
+
+
+def test_compound_assignment():
+    v, w = Vector2(3, -4), Vector2(5, 6)
+
+    acc = v.copy()
+    ident = acc
+    acc += w
+    assert acc == v + w
+    assert acc is ident  # updated in place
+
+    acc = v.copy()
+    acc -= w
+    assert acc == v - w
+
+    acc = v.copy()
+    acc *= 3
+    assert acc == v * 3
+
+    acc = Vector2(6.0, -9.0)
+    acc /= 2.0
+    assert acc == Vector2(6.0, -9.0) / 2.0
+
+    total = Vector2(0, 0)
+    for d in (v, w, -v):
+        total += d
+    assert total == w
+    assert v == Vector2(3, -4)  # operands are not modified
+
+
+def test_projection():
+    v = Vector2(3, 3)
+    assert v.dot(Vector2(1, 0)) == 3
+    assert v.project_onto(Vector2(1, 0)) == Vector2(3.0, 0.0)
+    assert v.project_onto(Vector2(0, -5)) == Vector2(0.0, 3.0)
+    assert v.scalar_projection(Vector2(1, 0)) == 3.0
+    assert v.scalar_projection(Vector2(0, -5)) == -3.0
+    assert v.project_onto(Vector2(0, 0)) == Vector2(0.0, 0.0)
+    assert v.scalar_projection(Vector2(0, 0)) == 0.0
+
+    w = Vector2(2, 7)
+    p = w.project_onto(v)
+    r = w - p
+    assert r.dot(v) == pytest.approx(0.0)  # the rest is orthogonal
+    assert p.magnitude() == pytest.approx(abs(w.scalar_projection(v)))
+
+
+def test_vector2_conversions():
+    assert Vector2.from_tuple((1, 2)) == Vector2(1, 2)
+    assert Vector2(1, 2).to_tuple() == (1, 2)
+    x, y = Vector2(-3, 4).to_tuple()
+    assert (x, y) == (-3, 4)
+    assert Vector2(1, 2).as_point().as_vector() == Vector2(1, 2)